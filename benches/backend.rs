@@ -0,0 +1,98 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion, Throughput,
+};
+use ratatui::{
+    backend::{AnsiBackend, Backend, CrosstermBackend},
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget, Wrap},
+};
+
+/// Benchmark for drawing the difference between two buffers with each backend. Besides the time
+/// spent, the throughput of each benchmark is the number of bytes sent to the terminal, which
+/// allows comparing how compact the output of the backends is.
+pub fn backend(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backend");
+    let area = Rect::new(0, 0, 200, 50);
+    let empty = Buffer::empty(area);
+    let text = paragraph_buffer(area, Style::default());
+    let styled = striped_buffer(area);
+    let mut cleared = Buffer::empty(area);
+    cleared.set_style(area, Style::default().bg(Color::Blue));
+    let mut sparse = text.clone();
+    for i in 0..area.height {
+        sparse.get_mut((i * 37) % area.width, i).set_char('#');
+    }
+
+    bench_diff(&mut group, "full", &empty, &text);
+    bench_diff(&mut group, "styled", &empty, &styled);
+    bench_diff(&mut group, "sparse", &text, &sparse);
+    bench_diff(&mut group, "clear", &text, &cleared);
+    group.finish();
+}
+
+/// Draws the diff segments from `previous` to `next` with both the crossterm and the ANSI backends.
+fn bench_diff(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    previous: &Buffer,
+    next: &Buffer,
+) {
+    let (width, height) = (next.area.width, next.area.height);
+
+    let mut output = Vec::new();
+    CrosstermBackend::new(&mut output)
+        .with_screen_width(width)
+        .draw_segments(previous.diff_segments(next).into_iter())
+        .unwrap();
+    group.throughput(Throughput::Bytes(output.len() as u64));
+    group.bench_function(BenchmarkId::new("crossterm", name), |b| {
+        b.iter(|| {
+            let mut backend =
+                CrosstermBackend::new(Vec::with_capacity(output.len())).with_screen_width(width);
+            backend.draw_segments(black_box(previous.diff_segments(next)).into_iter())
+        })
+    });
+
+    let mut output = Vec::new();
+    AnsiBackend::new(&mut output, width, height)
+        .draw_segments(previous.diff_segments(next).into_iter())
+        .unwrap();
+    group.throughput(Throughput::Bytes(output.len() as u64));
+    group.bench_function(BenchmarkId::new("ansi", name), |b| {
+        b.iter(|| {
+            let mut backend = AnsiBackend::new(Vec::with_capacity(output.len()), width, height);
+            backend.draw_segments(black_box(previous.diff_segments(next)).into_iter())
+        })
+    });
+}
+
+/// A buffer filled with wrapped nonsense words
+fn paragraph_buffer(area: Rect, style: Style) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    let text = fakeit::words::paragraph(area.height.into(), 3, 11, "\n".into());
+    Paragraph::new(text)
+        .style(style)
+        .wrap(Wrap { trim: true })
+        .render(area, &mut buffer);
+    buffer
+}
+
+/// A buffer of text where every line uses a different style
+fn striped_buffer(area: Rect) -> Buffer {
+    let mut buffer = paragraph_buffer(area, Style::default());
+    let colors = [Color::Red, Color::Indexed(42), Color::Rgb(30, 30, 46)];
+    for y in area.top()..area.bottom() {
+        let color = colors[usize::from(y) % colors.len()];
+        buffer.set_style(
+            Rect::new(area.x, y, area.width, 1),
+            Style::default().fg(color),
+        );
+    }
+    buffer
+}
+
+criterion_group!(benches, backend);
+criterion_main!(benches);
@@ -0,0 +1,114 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion, Throughput,
+};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Paragraph, Widget, Wrap},
+};
+
+/// Global allocator counting the number of allocations, so that the benchmarks can report how
+/// many allocations each operation performs besides the time spent.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Benchmark for the basic operations on large buffers: creating, cloning, writing to and diffing
+/// them. The throughput is the number of cells of the buffer.
+pub fn buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer");
+    for (width, height) in [(200, 50), (400, 120)] {
+        let area = Rect::new(0, 0, width, height);
+        let size = format!("{width}x{height}");
+        group.throughput(Throughput::Elements(area.area() as u64));
+
+        bench(&mut group, "empty", &size, || {
+            Buffer::empty(black_box(area))
+        });
+
+        let text = paragraph_buffer(area);
+        bench(&mut group, "clone", &size, || black_box(&text).clone());
+
+        let mut buffer = Buffer::empty(area);
+        let line = "ab称号cd".repeat(width as usize / 6);
+        bench(&mut group, "set_string", &size, || {
+            for y in 0..height {
+                buffer.set_string(0, y, black_box(&line), Style::default());
+            }
+        });
+
+        let mut buffer = Buffer::empty(area);
+        bench(&mut group, "set_symbol", &size, || {
+            for cell in buffer.content_mut().iter_mut() {
+                cell.set_symbol(black_box("█"));
+            }
+        });
+
+        let empty = Buffer::empty(area);
+        bench(&mut group, "diff_full", &size, || {
+            black_box(&empty).diff(black_box(&text)).len()
+        });
+        bench(&mut group, "diff_none", &size, || {
+            black_box(&text).diff(black_box(&text)).len()
+        });
+        let mut sparse = text.clone();
+        for y in 0..height {
+            sparse.get_mut((y * 37) % width, y).set_char('#');
+        }
+        bench(&mut group, "diff_sparse", &size, || {
+            black_box(&text).diff(black_box(&sparse)).len()
+        });
+    }
+    group.finish();
+}
+
+/// Runs a benchmark after reporting the number of allocations performed by one call of `f`.
+fn bench<O, F>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, size: &str, mut f: F)
+where
+    F: FnMut() -> O,
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(f());
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!("buffer/{name}/{size}: {allocations} allocations");
+    group.bench_function(BenchmarkId::new(name, size), |b| b.iter(&mut f));
+}
+
+/// Returns a buffer filled with wrapped text on a styled background
+fn paragraph_buffer(area: Rect) -> Buffer {
+    let mut buffer = Buffer::filled(area, Cell::default().set_bg(Color::Blue));
+    let text = fakeit::words::paragraph(area.height as i64, 3, 11, "\n".into());
+    Paragraph::new(text)
+        .wrap(Wrap { trim: false })
+        .render(area, &mut buffer);
+    buffer
+}
+
+criterion_group!(benches, buffer);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    Criterion, Throughput,
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+/// Benchmark for the work done by [`Terminal::flush`] on each frame: resetting the buffer, rendering
+/// the widgets and computing the difference with the previous frame. The throughput is the number
+/// of cells of the screen.
+///
+/// [`Terminal::flush`]: ratatui::Terminal::flush
+pub fn diff(c: &mut Criterion) {
+    let mut group = c.benchmark_group("diff");
+    for (width, height) in [(200, 50), (400, 120)] {
+        let area = Rect::new(0, 0, width, height);
+        let size = format!("{width}x{height}");
+        group.throughput(Throughput::Elements(area.area() as u64));
+        let text = fakeit::words::paragraph(height as i64 * 2, 3, 11, "\n".into());
+
+        // a small popup in the middle of an otherwise empty screen
+        let popup = Rect::new(width / 4, height / 4, width / 2, 5);
+        bench_frames(&mut group, "sparse", &size, area, |_, buffer| {
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Popup")
+                .render(popup, buffer);
+        });
+
+        // the whole screen is filled with the same text on each frame
+        bench_frames(&mut group, "static", &size, area, |_, buffer| {
+            Paragraph::new(text.as_str())
+                .wrap(Wrap { trim: false })
+                .render(area, buffer);
+        });
+
+        // the whole screen is filled with text scrolling by one line on each frame
+        bench_frames(&mut group, "scrolling", &size, area, |frame, buffer| {
+            Paragraph::new(text.as_str())
+                .wrap(Wrap { trim: false })
+                .scroll((frame % height, 0))
+                .render(area, buffer);
+        });
+    }
+    group.finish();
+}
+
+/// Renders frames with `render` the same way [`Terminal::draw`] does, the frame number being given
+/// to `render`.
+///
+/// [`Terminal::draw`]: ratatui::Terminal::draw
+fn bench_frames<F>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    size: &str,
+    area: Rect,
+    mut render: F,
+) where
+    F: FnMut(u16, &mut Buffer),
+{
+    let mut buffers = [Buffer::empty(area), Buffer::empty(area)];
+    let mut current = 0;
+    let mut frame: u16 = 0;
+    group.bench_function(BenchmarkId::new(name, size), |b| {
+        b.iter(|| {
+            render(frame, &mut buffers[current]);
+            let updates = buffers[1 - current].diff(&buffers[current]).len();
+            buffers[1 - current].reset();
+            current = 1 - current;
+            frame = frame.wrapping_add(1);
+            black_box(updates)
+        })
+    });
+}
+
+criterion_group!(benches, diff);
+criterion_main!(benches);
@@ -0,0 +1,543 @@
+//! This module provides the `AnsiBackend` implementation for the [`Backend`] trait.
+//! It writes ANSI escape sequences directly to any type implementing `Write`.
+//!
+//! [`Backend`]: trait.Backend.html
+
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use crate::{
+    backend::{is_clearable, Backend, ClearType},
+    buffer::{Cell, DiffSegment},
+    layout::Rect,
+    style::{Color, Modifier},
+    text::DisplayWidth,
+};
+
+/// Minimum number of trailing blank cells for which an erase-to-end-of-line sequence (`ESC [ K`,
+/// 3 bytes) is cheaper than printing the blanks.
+const MIN_ERASE_RUN: usize = 4;
+
+/// A backend implementation writing ANSI escape sequences to any type implementing `Write`.
+///
+/// `AnsiBackend` does not depend on any terminal library. Instead, it keeps track of the cursor
+/// position and of the current style to send as few bytes as possible to the terminal:
+///
+/// * the cursor is moved with relative sequences (carriage return, line feed and cursor forward)
+///   whenever they are shorter than an absolute move,
+/// * style changes are merged into a single SGR sequence,
+/// * runs of blank cells reaching the end of a line are cleared with erase-to-end-of-line.
+///
+/// As it cannot query the terminal, the size of the screen is given on creation and must be kept
+/// up to date with [`AnsiBackend::resize`]. Setting up raw mode and the alternate screen is left
+/// to the caller.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::backend::{AnsiBackend, Backend};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let buffer = std::io::stdout();
+/// let mut backend = AnsiBackend::new(buffer, 80, 24);
+/// backend.clear()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AnsiBackend<W: Write> {
+    buffer: W,
+    width: u16,
+    height: u16,
+    /// Current position of the cursor, `None` when unknown (e.g. pending wrap at the end of a
+    /// line or after raw writes through the `Write` implementation)
+    cursor: Option<(u16, u16)>,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+impl<W> AnsiBackend<W>
+where
+    W: Write,
+{
+    /// Creates a new `AnsiBackend` with the given buffer and screen size.
+    pub fn new(buffer: W, width: u16, height: u16) -> AnsiBackend<W> {
+        AnsiBackend {
+            buffer,
+            width,
+            height,
+            cursor: None,
+            fg: Color::Reset,
+            bg: Color::Reset,
+            modifier: Modifier::empty(),
+        }
+    }
+
+    /// Returns a reference to the underlying buffer.
+    pub fn buffer(&self) -> &W {
+        &self.buffer
+    }
+
+    /// Returns a mutable reference to the underlying buffer.
+    pub fn buffer_mut(&mut self) -> &mut W {
+        &mut self.buffer
+    }
+
+    /// Updates the size of the screen reported by [`Backend::size`].
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+        self.cursor = None;
+    }
+
+    /// Moves the cursor to (x, y) using the shortest sequence available.
+    fn move_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        let Some((cx, cy)) = self.cursor else {
+            return self.move_to_absolute(x, y);
+        };
+        if (cx, cy) == (x, y) {
+            return Ok(());
+        }
+        let absolute = 4 + digits(y + 1) + digits(x + 1);
+        let forward = if cy == y && x > cx {
+            cursor_forward_len(x - cx)
+        } else {
+            usize::MAX
+        };
+        let line_feeds = if y >= cy {
+            1 + usize::from(y - cy) + cursor_forward_len(x)
+        } else {
+            usize::MAX
+        };
+
+        if forward <= line_feeds && forward <= absolute {
+            self.cursor_forward(x - cx)?;
+        } else if line_feeds <= absolute {
+            self.buffer.write_all(b"\r")?;
+            for _ in cy..y {
+                self.buffer.write_all(b"\n")?;
+            }
+            self.cursor_forward(x)?;
+        } else {
+            return self.move_to_absolute(x, y);
+        }
+        self.cursor = Some((x, y));
+        Ok(())
+    }
+
+    fn move_to_absolute(&mut self, x: u16, y: u16) -> io::Result<()> {
+        write!(self.buffer, "\x1b[{};{}H", y + 1, x + 1)?;
+        self.cursor = Some((x, y));
+        Ok(())
+    }
+
+    fn cursor_forward(&mut self, n: u16) -> io::Result<()> {
+        match n {
+            0 => Ok(()),
+            1 => self.buffer.write_all(b"\x1b[C"),
+            n => write!(self.buffer, "\x1b[{n}C"),
+        }
+    }
+
+    /// Applies the style of the given cell with a single SGR sequence, choosing between an
+    /// incremental update and a full reset depending on which is shorter.
+    fn set_style(&mut self, cell: &Cell) -> io::Result<()> {
+        if cell.fg == self.fg && cell.bg == self.bg && cell.modifier == self.modifier {
+            return Ok(());
+        }
+
+        let mut incremental = String::new();
+        let removed = self.modifier - cell.modifier;
+        let mut added = cell.modifier - self.modifier;
+        if removed.intersects(Modifier::BOLD | Modifier::DIM) {
+            // there is a single code to disable both bold and dim
+            push_param(&mut incremental, "22");
+            added |= cell.modifier & (Modifier::BOLD | Modifier::DIM);
+        }
+        if removed.intersects(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK) {
+            push_param(&mut incremental, "25");
+            added |= cell.modifier & (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
+        }
+        for (modifier, code) in [
+            (Modifier::ITALIC, "23"),
+            (Modifier::UNDERLINED, "24"),
+            (Modifier::REVERSED, "27"),
+            (Modifier::HIDDEN, "28"),
+            (Modifier::CROSSED_OUT, "29"),
+        ] {
+            if removed.contains(modifier) {
+                push_param(&mut incremental, code);
+            }
+        }
+        push_modifiers(&mut incremental, added);
+        if cell.fg != self.fg {
+            push_color(&mut incremental, cell.fg, false);
+        }
+        if cell.bg != self.bg {
+            push_color(&mut incremental, cell.bg, true);
+        }
+
+        let reset = reset_params(cell);
+        let params = if reset.len() < incremental.len() {
+            reset
+        } else {
+            incremental
+        };
+        write!(self.buffer, "\x1b[{params}m")?;
+        self.fg = cell.fg;
+        self.bg = cell.bg;
+        self.modifier = cell.modifier;
+        Ok(())
+    }
+
+    fn reset_style(&mut self) -> io::Result<()> {
+        if self.fg != Color::Reset || self.bg != Color::Reset || !self.modifier.is_empty() {
+            self.buffer.write_all(b"\x1b[0m")?;
+            self.fg = Color::Reset;
+            self.bg = Color::Reset;
+            self.modifier = Modifier::empty();
+        }
+        Ok(())
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        self.move_to(x, y)?;
+        self.set_style(cell)?;
+        self.buffer.write_all(cell.symbol().as_bytes())?;
+        // Once the last column is written the terminal is in a "pending wrap" state that relative
+        // moves cannot account for.
+        let next_x = x.saturating_add(cell.symbol().width() as u16);
+        self.cursor = (next_x < self.width).then_some((next_x, y));
+        Ok(())
+    }
+
+    /// Clears the line from (x, y) with the background color of the given cell.
+    fn clear_until_new_line(&mut self, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        self.move_to(x, y)?;
+        self.set_style(cell)?;
+        // erasing does not move the cursor
+        self.buffer.write_all(b"\x1b[K")
+    }
+
+    /// Returns the number of cells at the start of `content` that form a run of blank cells
+    /// sharing the same background and reaching the right edge of the screen, if that run is long
+    /// enough to be worth erasing.
+    fn erasable_run(&self, content: &[(u16, u16, &Cell)]) -> Option<usize> {
+        let (x, y, first) = *content.first()?;
+        let run = usize::from(self.width.checked_sub(x)?);
+        if run < MIN_ERASE_RUN || content.len() < run {
+            return None;
+        }
+        let erasable = content[..run]
+            .iter()
+            .zip(x..)
+            .all(|(&(cx, cy, cell), expected_x)| {
+                cx == expected_x
+                    && cy == y
+                    && cell.symbol() == " "
+                    && cell.bg == first.bg
+                    && is_clearable(cell)
+            });
+        erasable.then_some(run)
+    }
+}
+
+impl<W> Write for AnsiBackend<W>
+where
+    W: Write,
+{
+    /// Writes a buffer of bytes to the underlying buffer.
+    ///
+    /// The backend cannot know where the cursor ends up, so the next draw starts with an absolute
+    /// move.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.cursor = None;
+        self.buffer.write(buf)
+    }
+
+    /// Flushes the underlying buffer.
+    fn flush(&mut self) -> io::Result<()> {
+        self.buffer.flush()
+    }
+}
+
+impl<W> Backend for AnsiBackend<W>
+where
+    W: Write,
+{
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let content: Vec<(u16, u16, &Cell)> = content.collect();
+        let mut i = 0;
+        while i < content.len() {
+            let (x, y, cell) = content[i];
+            if let Some(run) = self.erasable_run(&content[i..]) {
+                self.clear_until_new_line(x, y, cell)?;
+                i += run;
+            } else {
+                self.draw_cell(x, y, cell)?;
+                i += 1;
+            }
+        }
+        self.reset_style()
+    }
+
+    fn draw_segments<'a, I>(&mut self, segments: I) -> io::Result<()>
+    where
+        I: Iterator<Item = DiffSegment<'a>>,
+    {
+        for segment in segments {
+            match segment {
+                DiffSegment::ClearToEndOfLine { x, y, width, cell }
+                    if x.saturating_add(width) == self.width
+                        && usize::from(width) >= MIN_ERASE_RUN
+                        && is_clearable(cell) =>
+                {
+                    self.clear_until_new_line(x, y, cell)?;
+                }
+                segment => {
+                    for (x, y, cell) in segment.into_cells() {
+                        self.draw_cell(x, y, cell)?;
+                    }
+                }
+            }
+        }
+        self.reset_style()
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        for _ in 0..n {
+            self.buffer.write_all(b"\n")?;
+        }
+        self.cursor = None;
+        self.buffer.flush()
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.buffer.write_all(b"\x1b[?25l")?;
+        self.buffer.flush()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.buffer.write_all(b"\x1b[?25h")?;
+        self.buffer.flush()
+    }
+
+    /// Returns the last position the backend moved the cursor to.
+    ///
+    /// The terminal cannot be queried through a `Write`, so this fails when the position is not
+    /// known.
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.cursor
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "the cursor position is not known"))
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.move_to(x, y)?;
+        self.buffer.flush()
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.clear_region(ClearType::All)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let sequence: &[u8] = match clear_type {
+            ClearType::All => b"\x1b[2J",
+            ClearType::AfterCursor => b"\x1b[J",
+            ClearType::BeforeCursor => b"\x1b[1J",
+            ClearType::CurrentLine => b"\x1b[2K",
+            ClearType::UntilNewLine => b"\x1b[K",
+        };
+        self.buffer.write_all(sequence)?;
+        self.buffer.flush()
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        Ok(Rect::new(0, 0, self.width, self.height))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.buffer.flush()
+    }
+}
+
+/// Number of decimal digits needed to print `n`.
+fn digits(n: u16) -> usize {
+    match n {
+        0..=9 => 1,
+        10..=99 => 2,
+        100..=999 => 3,
+        1000..=9999 => 4,
+        _ => 5,
+    }
+}
+
+/// Length in bytes of the sequence moving the cursor `n` columns to the right.
+fn cursor_forward_len(n: u16) -> usize {
+    match n {
+        0 => 0,
+        1 => 3,
+        n => 3 + digits(n),
+    }
+}
+
+/// Returns the parameters of an SGR sequence setting the style of the given cell from scratch:
+/// all the attributes are reset first.
+pub(crate) fn reset_params(cell: &Cell) -> String {
+    let mut params = String::from("0");
+    push_modifiers(&mut params, cell.modifier);
+    if cell.fg != Color::Reset {
+        push_color(&mut params, cell.fg, false);
+    }
+    if cell.bg != Color::Reset {
+        push_color(&mut params, cell.bg, true);
+    }
+    params
+}
+
+fn push_param(params: &mut String, param: &str) {
+    if !params.is_empty() {
+        params.push(';');
+    }
+    params.push_str(param);
+}
+
+fn push_modifiers(params: &mut String, modifier: Modifier) {
+    for (flag, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if modifier.contains(flag) {
+            push_param(params, code);
+        }
+    }
+}
+
+fn push_color(params: &mut String, color: Color, background: bool) {
+    if !params.is_empty() {
+        params.push(';');
+    }
+    let offset = if background { 10 } else { 0 };
+    let _ = match color {
+        Color::Indexed(i) => write!(params, "{};5;{i}", 38 + offset),
+        Color::Rgb(r, g, b) => write!(params, "{};2;{r};{g};{b}", 38 + offset),
+        Color::Reset => write!(params, "{}", 39 + offset),
+        Color::Black => write!(params, "{}", 30 + offset),
+        Color::Red => write!(params, "{}", 31 + offset),
+        Color::Green => write!(params, "{}", 32 + offset),
+        Color::Yellow => write!(params, "{}", 33 + offset),
+        Color::Blue => write!(params, "{}", 34 + offset),
+        Color::Magenta => write!(params, "{}", 35 + offset),
+        Color::Cyan => write!(params, "{}", 36 + offset),
+        Color::Gray => write!(params, "{}", 37 + offset),
+        Color::DarkGray => write!(params, "{}", 90 + offset),
+        Color::LightRed => write!(params, "{}", 91 + offset),
+        Color::LightGreen => write!(params, "{}", 92 + offset),
+        Color::LightYellow => write!(params, "{}", 93 + offset),
+        Color::LightBlue => write!(params, "{}", 94 + offset),
+        Color::LightMagenta => write!(params, "{}", 95 + offset),
+        Color::LightCyan => write!(params, "{}", 96 + offset),
+        Color::White => write!(params, "{}", 97 + offset),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buffer::Buffer, style::Style};
+
+    fn draw(backend: &mut AnsiBackend<Vec<u8>>, previous: &Buffer, next: &Buffer) -> String {
+        backend.buffer_mut().clear();
+        backend.draw(previous.diff(next).into_iter()).unwrap();
+        String::from_utf8(backend.buffer().clone()).unwrap()
+    }
+
+    #[test]
+    fn draws_adjacent_cells_without_moving() {
+        let mut backend = AnsiBackend::new(Vec::new(), 10, 2);
+        let previous = Buffer::empty(Rect::new(0, 0, 10, 2));
+        let next = Buffer::with_lines(vec!["abc       ", "          "]);
+        assert_eq!(draw(&mut backend, &previous, &next), "\x1b[1;1Habc");
+    }
+
+    #[test]
+    fn moves_relatively_when_shorter() {
+        let mut backend = AnsiBackend::new(Vec::new(), 20, 3);
+        let previous = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let next = Buffer::with_lines(vec!["a   b               ", "c                   "]);
+        assert_eq!(
+            draw(&mut backend, &previous, &next),
+            "\x1b[1;1Ha\x1b[3Cb\r\nc"
+        );
+    }
+
+    #[test]
+    fn wide_characters_advance_the_cursor() {
+        let mut backend = AnsiBackend::new(Vec::new(), 10, 1);
+        let previous = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let next = Buffer::with_lines(vec!["称号a      "]);
+        assert_eq!(draw(&mut backend, &previous, &next), "\x1b[1;1H称号a");
+    }
+
+    #[test]
+    fn merges_style_changes_into_one_sequence() {
+        let mut backend = AnsiBackend::new(Vec::new(), 10, 1);
+        let previous = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut next = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let style = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Rgb(1, 2, 3))
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC);
+        next.set_string(0, 0, "ab", style);
+        next.set_string(2, 0, "c", Style::default().fg(Color::Red));
+        assert_eq!(
+            draw(&mut backend, &previous, &next),
+            "\x1b[1;1H\x1b[1;3;31;48;2;1;2;3mab\x1b[0;31mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn erases_trailing_blank_runs() {
+        let mut backend = AnsiBackend::new(Vec::new(), 10, 1);
+        let previous = Buffer::with_lines(vec!["abcdefghij"]);
+        let mut next = Buffer::empty(Rect::new(0, 0, 10, 1));
+        next.set_string(0, 0, "xy", Style::default());
+        next.set_style(Rect::new(2, 0, 8, 1), Style::default().bg(Color::Blue));
+        assert_eq!(
+            draw(&mut backend, &previous, &next),
+            "\x1b[1;1Hxy\x1b[44m\x1b[K\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn erases_clear_to_end_of_line_segments() {
+        let mut backend = AnsiBackend::new(Vec::new(), 10, 1);
+        let previous = Buffer::with_lines(vec!["ab      cd"]);
+        let next = Buffer::empty(Rect::new(0, 0, 10, 1));
+        backend
+            .draw_segments(previous.diff_segments(&next).into_iter())
+            .unwrap();
+        assert_eq!(backend.buffer().as_slice(), b"\x1b[1;1H\x1b[K");
+    }
+
+    #[test]
+    fn does_not_erase_short_runs() {
+        let mut backend = AnsiBackend::new(Vec::new(), 5, 1);
+        let previous = Buffer::with_lines(vec!["abcde"]);
+        let next = Buffer::with_lines(vec!["ab   "]);
+        assert_eq!(draw(&mut backend, &previous, &next), "\x1b[1;3H   ");
+    }
+}
@@ -5,7 +5,10 @@
 //! [`Backend`]: trait.Backend.html
 //! [`CrosstermBackend`]: struct.CrosstermBackend.html
 
-use std::io::{self, Write};
+use std::{
+    io::{self, Write},
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
@@ -14,12 +17,12 @@ use crossterm::{
         Attribute as CAttribute, Color as CColor, Print, SetAttribute, SetBackgroundColor,
         SetForegroundColor,
     },
-    terminal::{self, Clear},
+    terminal::{self, Clear, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
 use crate::{
-    backend::{Backend, ClearType},
-    buffer::Cell,
+    backend::{is_clearable, Backend, ClearType},
+    buffer::{Cell, DiffSegment},
     layout::Rect,
     style::{Color, Modifier},
 };
@@ -44,15 +47,107 @@ use crate::{
 /// ```
 pub struct CrosstermBackend<W: Write> {
     buffer: W,
+    /// Width of the screen as of the last size query, or [`UNKNOWN_WIDTH`]
+    screen_width: AtomicU32,
 }
 
+/// Value of [`CrosstermBackend::screen_width`] before the size of the screen is known
+const UNKNOWN_WIDTH: u32 = u32::MAX;
+
 impl<W> CrosstermBackend<W>
 where
     W: Write,
 {
     /// Creates a new `CrosstermBackend` with the given buffer.
     pub fn new(buffer: W) -> CrosstermBackend<W> {
-        CrosstermBackend { buffer }
+        CrosstermBackend {
+            buffer,
+            screen_width: AtomicU32::new(UNKNOWN_WIDTH),
+        }
+    }
+
+    /// Sets the width of the screen, which is otherwise queried from the terminal.
+    ///
+    /// The width is used to clear the ends of lines instead of drawing blank cells. It is updated
+    /// each time the size of the terminal is queried with [`Backend::size`], which
+    /// [`Terminal`](crate::Terminal) does before each draw to detect resizes, so this is only
+    /// useful when the output is not a terminal, e.g. to measure the output of the backend.
+    pub fn with_screen_width(self, width: u16) -> CrosstermBackend<W> {
+        self.screen_width.store(u32::from(width), Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the width of the screen, querying it only if it is not known yet.
+    fn screen_width(&self) -> Option<u16> {
+        match self.screen_width.load(Ordering::Relaxed) {
+            UNKNOWN_WIDTH => self.size().ok().map(|size| size.width),
+            width => u16::try_from(width).ok(),
+        }
+    }
+}
+
+/// Style and position of the last cell queued while drawing
+struct DrawState {
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+    last_pos: Option<(u16, u16)>,
+}
+
+impl Default for DrawState {
+    fn default() -> DrawState {
+        DrawState {
+            fg: Color::Reset,
+            bg: Color::Reset,
+            modifier: Modifier::empty(),
+            last_pos: None,
+        }
+    }
+}
+
+impl<W> CrosstermBackend<W>
+where
+    W: Write,
+{
+    fn queue_cell(&mut self, state: &mut DrawState, x: u16, y: u16, cell: &Cell) -> io::Result<()> {
+        // Move the cursor if the previous location was not (x - 1, y)
+        if !matches!(state.last_pos, Some(p) if x == p.0 + 1 && y == p.1) {
+            map_error(queue!(self.buffer, MoveTo(x, y)))?;
+        }
+        state.last_pos = Some((x, y));
+        self.queue_style(state, cell)?;
+        map_error(queue!(self.buffer, Print(cell.symbol())))
+    }
+
+    fn queue_style(&mut self, state: &mut DrawState, cell: &Cell) -> io::Result<()> {
+        if cell.modifier != state.modifier {
+            let diff = ModifierDiff {
+                from: state.modifier,
+                to: cell.modifier,
+            };
+            diff.queue(&mut self.buffer)?;
+            state.modifier = cell.modifier;
+        }
+        if cell.fg != state.fg {
+            let color = CColor::from(cell.fg);
+            map_error(queue!(self.buffer, SetForegroundColor(color)))?;
+            state.fg = cell.fg;
+        }
+        if cell.bg != state.bg {
+            let color = CColor::from(cell.bg);
+            map_error(queue!(self.buffer, SetBackgroundColor(color)))?;
+            state.bg = cell.bg;
+        }
+        Ok(())
+    }
+
+    fn queue_reset(&mut self) -> io::Result<()> {
+        map_error(queue!(
+            self.buffer,
+            SetForegroundColor(CColor::Reset),
+            SetBackgroundColor(CColor::Reset),
+            SetAttribute(CAttribute::Reset)
+        ))
     }
 }
 
@@ -79,44 +174,41 @@ where
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
-        let mut fg = Color::Reset;
-        let mut bg = Color::Reset;
-        let mut modifier = Modifier::empty();
-        let mut last_pos: Option<(u16, u16)> = None;
+        let mut state = DrawState::default();
         for (x, y, cell) in content {
-            // Move the cursor if the previous location was not (x - 1, y)
-            if !matches!(last_pos, Some(p) if x == p.0 + 1 && y == p.1) {
-                map_error(queue!(self.buffer, MoveTo(x, y)))?;
-            }
-            last_pos = Some((x, y));
-            if cell.modifier != modifier {
-                let diff = ModifierDiff {
-                    from: modifier,
-                    to: cell.modifier,
-                };
-                diff.queue(&mut self.buffer)?;
-                modifier = cell.modifier;
-            }
-            if cell.fg != fg {
-                let color = CColor::from(cell.fg);
-                map_error(queue!(self.buffer, SetForegroundColor(color)))?;
-                fg = cell.fg;
-            }
-            if cell.bg != bg {
-                let color = CColor::from(cell.bg);
-                map_error(queue!(self.buffer, SetBackgroundColor(color)))?;
-                bg = cell.bg;
-            }
-
-            map_error(queue!(self.buffer, Print(&cell.symbol)))?;
+            self.queue_cell(&mut state, x, y, cell)?;
         }
+        self.queue_reset()
+    }
 
-        map_error(queue!(
-            self.buffer,
-            SetForegroundColor(CColor::Reset),
-            SetBackgroundColor(CColor::Reset),
-            SetAttribute(CAttribute::Reset)
-        ))
+    fn draw_segments<'a, I>(&mut self, segments: I) -> io::Result<()>
+    where
+        I: Iterator<Item = DiffSegment<'a>>,
+    {
+        // the size is only used to clear lines, ignore it if it can't be queried
+        let screen_width = self.screen_width();
+        let mut state = DrawState::default();
+        for segment in segments {
+            match segment {
+                DiffSegment::ClearToEndOfLine { x, y, width, cell }
+                    if screen_width == Some(x.saturating_add(width)) && is_clearable(cell) =>
+                {
+                    map_error(queue!(self.buffer, MoveTo(x, y)))?;
+                    self.queue_style(&mut state, cell)?;
+                    map_error(queue!(
+                        self.buffer,
+                        Clear(crossterm::terminal::ClearType::UntilNewLine)
+                    ))?;
+                    state.last_pos = None;
+                }
+                segment => {
+                    for (x, y, cell) in segment.into_cells() {
+                        self.queue_cell(&mut state, x, y, cell)?;
+                    }
+                }
+            }
+        }
+        self.queue_reset()
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
@@ -160,9 +252,20 @@ where
         self.buffer.flush()
     }
 
+    fn suspend(&mut self) -> io::Result<()> {
+        map_error(execute!(self.buffer, LeaveAlternateScreen, Show))?;
+        map_error(terminal::disable_raw_mode())
+    }
+
+    fn resume(&mut self) -> io::Result<()> {
+        map_error(terminal::enable_raw_mode())?;
+        map_error(execute!(self.buffer, EnterAlternateScreen))
+    }
+
     fn size(&self) -> io::Result<Rect> {
         let (width, height) =
             terminal::size().map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        self.screen_width.store(u32::from(width), Ordering::Relaxed);
 
         Ok(Rect::new(0, 0, width, height))
     }
@@ -272,3 +375,27 @@ impl ModifierDiff {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn draw_segments_clears_to_end_of_line_with_known_width() {
+        let previous = Buffer::with_lines(vec!["abcdef"]);
+        let next = Buffer::with_lines(vec!["ab    "]);
+        let draw = |backend: &mut CrosstermBackend<Vec<u8>>| {
+            backend
+                .draw_segments(previous.diff_segments(&next).into_iter())
+                .unwrap();
+            String::from_utf8(backend.buffer.clone()).unwrap()
+        };
+
+        let output = draw(&mut CrosstermBackend::new(vec![]).with_screen_width(6));
+        assert!(output.contains("\x1b[K"));
+        // the cells are drawn one by one when the line does not reach the edge of the screen
+        let output = draw(&mut CrosstermBackend::new(vec![]).with_screen_width(10));
+        assert!(!output.contains("\x1b[K"));
+    }
+}
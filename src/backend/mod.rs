@@ -7,7 +7,8 @@
 //! - Termion (with the `termion` feature)
 //! - Termwiz (with the `termwiz` feature)
 //!
-//! Additionally, a [`TestBackend`] is provided for testing purposes.
+//! An [`AnsiBackend`], writing ANSI escape sequences to any `Write` without relying on a terminal
+//! library, is always available. Additionally, a [`TestBackend`] is provided for testing purposes.
 //!
 //! # Example
 //!
@@ -23,11 +24,16 @@
 //! ```
 //!
 //! [`Backend`]: trait.Backend.html
+//! [`AnsiBackend`]: struct.AnsiBackend.html
 //! [`TestBackend`]: struct.TestBackend.html
 
 use std::io;
 
-use crate::{buffer::Cell, layout::Rect};
+use crate::{
+    buffer::{Cell, DiffSegment},
+    layout::Rect,
+    style::Modifier,
+};
 
 #[cfg(feature = "termion")]
 mod termion;
@@ -44,8 +50,12 @@ mod termwiz;
 #[cfg(feature = "termwiz")]
 pub use self::termwiz::TermwizBackend;
 
+mod ansi;
+pub(crate) use self::ansi::reset_params;
+pub use self::ansi::AnsiBackend;
+
 mod test;
-pub use self::test::TestBackend;
+pub use self::test::{TestBackend, TestFrame};
 
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
@@ -58,6 +68,17 @@ pub enum ClearType {
     UntilNewLine,
 }
 
+/// Returns whether clearing a cell with the background color of `cell` looks the same as drawing
+/// `cell` (which is expected to be blank).
+///
+/// Some modifiers, like underline, are visible on blank cells but are not applied by the clear
+/// operations of terminals.
+pub(crate) fn is_clearable(cell: &Cell) -> bool {
+    !cell
+        .modifier
+        .intersects(Modifier::UNDERLINED | Modifier::REVERSED | Modifier::CROSSED_OUT)
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries.
 /// It defines the methods required to draw content, manipulate the cursor, and
 /// clear the terminal screen.
@@ -71,6 +92,20 @@ pub trait Backend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>;
 
+    /// Draw the given segments, as computed by [`Buffer::diff_segments`], to the terminal screen.
+    ///
+    /// Backends can override this method to take advantage of the segments, e.g. by replacing
+    /// [`DiffSegment::ClearToEndOfLine`] with a single clear operation. The default
+    /// implementation draws every cell of the segments with [`Backend::draw`].
+    ///
+    /// [`Buffer::diff_segments`]: crate::buffer::Buffer::diff_segments
+    fn draw_segments<'a, I>(&mut self, segments: I) -> Result<(), io::Error>
+    where
+        I: Iterator<Item = DiffSegment<'a>>,
+    {
+        self.draw(segments.flat_map(DiffSegment::into_cells))
+    }
+
     /// Insert `n` line breaks to the terminal screen.
     ///
     /// This method is optional and may not be implemented by all backends.
@@ -109,6 +144,28 @@ pub trait Backend {
         }
     }
 
+    /// Restore the terminal to its normal state so that other programs can use it, by leaving the
+    /// alternate screen and raw mode and showing the cursor.
+    ///
+    /// This is used by [`Terminal::suspend`](crate::Terminal::suspend). This method is optional
+    /// and may not be implemented by all backends.
+    fn suspend(&mut self) -> Result<(), io::Error> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "suspend is not supported with this backend",
+        ))
+    }
+
+    /// Enter the alternate screen and raw mode again after a call to [`Backend::suspend`].
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    fn resume(&mut self) -> Result<(), io::Error> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "resume is not supported with this backend",
+        ))
+    }
+
     /// Get the size of the terminal screen as a [`Rect`].
     fn size(&self) -> Result<Rect, io::Error>;
 
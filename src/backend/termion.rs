@@ -46,6 +46,18 @@ where
     pub fn new(stdout: W) -> TermionBackend<W> {
         TermionBackend { stdout }
     }
+
+    /// Returns the output of the backend, e.g. the [`RawTerminal`] to suspend the raw mode of.
+    ///
+    /// [`RawTerminal`]: termion::raw::RawTerminal
+    pub fn writer(&self) -> &W {
+        &self.stdout
+    }
+
+    /// Returns the output of the backend mutably.
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.stdout
+    }
 }
 
 impl<W> Write for TermionBackend<W>
@@ -97,6 +109,29 @@ where
         self.stdout.flush()
     }
 
+    /// Leaves the alternate screen and shows the cursor.
+    ///
+    /// The raw mode is not disabled, as it belongs to the [`RawTerminal`] given to the backend:
+    /// suspend it with `backend.writer().suspend_raw_mode()`.
+    ///
+    /// [`RawTerminal`]: termion::raw::RawTerminal
+    fn suspend(&mut self) -> io::Result<()> {
+        write!(
+            self.stdout,
+            "{}{}",
+            termion::screen::ToMainScreen,
+            termion::cursor::Show
+        )?;
+        self.stdout.flush()
+    }
+
+    /// Enters the alternate screen again. The raw mode is activated again with
+    /// `backend.writer().activate_raw_mode()`.
+    fn resume(&mut self) -> io::Result<()> {
+        write!(self.stdout, "{}", termion::screen::ToAlternateScreen)?;
+        self.stdout.flush()
+    }
+
     fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
         termion::cursor::DetectCursorPos::cursor_pos(&mut self.stdout).map(|(x, y)| (x - 1, y - 1))
     }
@@ -143,7 +178,7 @@ where
                 write!(string, "{}", Bg(cell.bg)).unwrap();
                 bg = cell.bg;
             }
-            string.push_str(&cell.symbol);
+            string.push_str(cell.symbol());
         }
         write!(
             self.stdout,
@@ -293,3 +328,24 @@ impl fmt::Display for ModifierDiff {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suspend_and_resume() {
+        let mut backend = TermionBackend::new(vec![]);
+        backend.suspend().unwrap();
+        assert_eq!(
+            backend.writer(),
+            format!("{}{}", termion::screen::ToMainScreen, termion::cursor::Show).as_bytes()
+        );
+        backend.writer_mut().clear();
+        backend.resume().unwrap();
+        assert_eq!(
+            backend.writer(),
+            termion::screen::ToAlternateScreen.to_string().as_bytes()
+        );
+    }
+}
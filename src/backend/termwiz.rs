@@ -131,7 +131,7 @@ impl Backend for TermwizBackend {
                     },
                 )));
 
-            self.buffered_terminal.add_change(&cell.symbol);
+            self.buffered_terminal.add_change(cell.symbol());
         }
         Ok(())
     }
@@ -168,6 +168,36 @@ impl Backend for TermwizBackend {
         Ok(())
     }
 
+    fn suspend(&mut self) -> Result<(), io::Error> {
+        self.show_cursor()?;
+        self.flush()?;
+        self.buffered_terminal
+            .terminal()
+            .exit_alternate_screen()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        self.buffered_terminal
+            .terminal()
+            .set_cooked_mode()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<(), io::Error> {
+        self.buffered_terminal
+            .terminal()
+            .set_raw_mode()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        self.buffered_terminal
+            .terminal()
+            .enter_alternate_screen()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        // the content of the screen is unknown after the other programs used it
+        self.buffered_terminal
+            .repaint()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         let (term_width, term_height) = self.buffered_terminal.dimensions();
         let max = u16::max_value();
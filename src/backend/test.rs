@@ -2,18 +2,22 @@
 //! It is used in the integration tests to verify the correctness of the library.
 
 use std::{
+    collections::VecDeque,
     fmt::{Display, Write},
     io,
 };
 
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     backend::Backend,
     buffer::{Buffer, Cell},
     layout::Rect,
+    style::Style,
+    text::DisplayWidth,
 };
 
+/// Number of frames kept by default in the history of a [`TestBackend`].
+const DEFAULT_HISTORY_CAPACITY: usize = 10;
+
 /// A backend used for the integration tests.
 ///
 /// # Example
@@ -35,6 +39,25 @@ pub struct TestBackend {
     height: u16,
     cursor: bool,
     pos: (u16, u16),
+    /// Frames recorded on each flush, the most recent last
+    history: VecDeque<TestFrame>,
+    history_capacity: usize,
+}
+
+/// The state of a [`TestBackend`] recorded each time it is flushed.
+///
+/// There is one `TestFrame` per flush. [`Terminal::draw`](crate::Terminal::draw) flushes the
+/// backend once all cells and the cursor have been updated, so each draw call records one frame,
+/// but other calls flush the backend too, e.g. [`Terminal::suspend`](crate::Terminal::suspend)
+/// and [`Terminal::resume`](crate::Terminal::resume).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestFrame {
+    /// Content of the screen
+    pub buffer: Buffer,
+    /// Position of the cursor
+    pub cursor_position: (u16, u16),
+    /// Whether the cursor is visible
+    pub cursor_visible: bool,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -44,18 +67,18 @@ pub struct TestBackend {
 /// If a cell is hidden by a multi-width symbol, it is added to the overwritten vector and
 /// displayed at the end of the line.
 fn buffer_view(buffer: &Buffer) -> String {
-    let mut view = String::with_capacity(buffer.content.len() + buffer.area.height as usize * 3);
-    for cells in buffer.content.chunks(buffer.area.width as usize) {
+    let mut view = String::with_capacity(buffer.content().len() + buffer.area.height as usize * 3);
+    for cells in buffer.content().chunks(buffer.area.width as usize) {
         let mut overwritten = vec![];
         let mut skip: usize = 0;
         view.push('"');
         for (x, c) in cells.iter().enumerate() {
             if skip == 0 {
-                view.push_str(&c.symbol);
+                view.push_str(c.symbol());
             } else {
-                overwritten.push((x, &c.symbol));
+                overwritten.push((x, c.symbol()));
             }
-            skip = std::cmp::max(skip, c.symbol.width()).saturating_sub(1);
+            skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
         }
         view.push('"');
         if !overwritten.is_empty() {
@@ -75,14 +98,61 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             cursor: false,
             pos: (0, 0),
+            history: VecDeque::new(),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
         }
     }
 
+    /// Sets the number of frames kept in the history. Older frames are dropped first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{backend::TestBackend, Terminal};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let backend = TestBackend::new(10, 2).with_history_capacity(2);
+    /// let mut terminal = Terminal::new(backend)?;
+    /// for _ in 0..5 {
+    ///     terminal.draw(|_| {})?;
+    /// }
+    /// assert_eq!(terminal.backend().history().count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_history_capacity(mut self, capacity: usize) -> TestBackend {
+        self.history_capacity = capacity;
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+        self
+    }
+
     /// Returns a reference to the internal buffer of the TestBackend.
     pub fn buffer(&self) -> &Buffer {
         &self.buffer
     }
 
+    /// Returns the current position of the cursor.
+    pub fn cursor_position(&self) -> (u16, u16) {
+        self.pos
+    }
+
+    /// Returns whether the cursor is currently visible.
+    pub fn is_cursor_visible(&self) -> bool {
+        self.cursor
+    }
+
+    /// Returns the recorded frames, from the oldest to the most recent.
+    pub fn history(&self) -> impl DoubleEndedIterator<Item = &TestFrame> {
+        self.history.iter()
+    }
+
+    /// Returns the frame recorded by the last flush, if any.
+    pub fn last_frame(&self) -> Option<&TestFrame> {
+        self.history.back()
+    }
+
     /// Resizes the TestBackend to the specified width and height.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
@@ -127,6 +197,109 @@ impl TestBackend {
         debug_info.push_str(&nice_diff);
         panic!("{debug_info}");
     }
+
+    /// Asserts that the cell at the given coordinates has the expected style.
+    ///
+    /// Only the properties set in `expected` are compared: a `Style` with only a foreground color
+    /// matches cells of that color whatever their background. Modifiers in `add_modifier` must be
+    /// present on the cell and those in `sub_modifier` must be absent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     backend::TestBackend,
+    ///     style::{Color, Style},
+    ///     widgets::Paragraph,
+    ///     Terminal,
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut terminal = Terminal::new(TestBackend::new(5, 1))?;
+    /// terminal.draw(|f| {
+    ///     let paragraph = Paragraph::new("Hello").style(Style::default().fg(Color::Red));
+    ///     f.render_widget(paragraph, f.size());
+    /// })?;
+    /// terminal
+    ///     .backend()
+    ///     .assert_cell_style(0, 0, Style::default().fg(Color::Red));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn assert_cell_style(&self, x: u16, y: u16, expected: Style) {
+        let cell = self.buffer.get(x, y);
+        let matches = expected.fg.unwrap_or(cell.fg) == cell.fg
+            && expected.bg.unwrap_or(cell.bg) == cell.bg
+            && cell.modifier.contains(expected.add_modifier)
+            && !cell.modifier.intersects(expected.sub_modifier);
+        assert!(
+            matches,
+            "Cell at ({x}, {y}) does not have the expected style\nExpected: {expected:?}\nGot: {cell:?}"
+        );
+    }
+
+    /// Asserts that the cursor is visible and placed at the given coordinates.
+    pub fn assert_cursor_visible_at(&self, x: u16, y: u16) {
+        assert!(
+            self.cursor,
+            "Expected the cursor to be visible at ({x}, {y}) but it is hidden"
+        );
+        assert_eq!(
+            self.pos,
+            (x, y),
+            "Expected the cursor to be at ({x}, {y}) but it is at {:?}",
+            self.pos
+        );
+    }
+
+    /// Asserts that the cursor is hidden.
+    pub fn assert_cursor_hidden(&self) {
+        assert!(
+            !self.cursor,
+            "Expected the cursor to be hidden but it is visible at {:?}",
+            self.pos
+        );
+    }
+
+    /// Returns a textual snapshot of the buffer including its styles.
+    ///
+    /// See [`Buffer::to_snapshot`] for the format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     backend::TestBackend,
+    ///     style::{Color, Modifier, Style},
+    ///     widgets::Paragraph,
+    ///     Terminal,
+    /// };
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut terminal = Terminal::new(TestBackend::new(8, 1))?;
+    /// terminal.draw(|f| {
+    ///     let style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    ///     f.render_widget(Paragraph::new("Hello").style(style), f.size());
+    /// })?;
+    /// assert_eq!(
+    ///     terminal.backend().styled_snapshot(),
+    ///     "\"Hello   \"\nstyles:\n\"aaaaaaaa\"\nlegend:\na: fg: Red, modifier: BOLD\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn styled_snapshot(&self) -> String {
+        self.buffer.to_snapshot()
+    }
+
+    /// Asserts that the buffer matches the expected styled snapshot.
+    ///
+    /// The snapshot is parsed with [`Buffer::from_snapshot`], so it can be indented, and compared
+    /// with [`TestBackend::assert_buffer`].
+    pub fn assert_styled_snapshot(&self, expected: &str) {
+        let expected = Buffer::from_snapshot(expected).unwrap_or_else(|err| panic!("{err}"));
+        self.assert_buffer(&expected);
+    }
 }
 
 impl Display for TestBackend {
@@ -173,11 +346,32 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn suspend(&mut self) -> Result<(), io::Error> {
+        self.cursor = true;
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         Ok(Rect::new(0, 0, self.width, self.height))
     }
 
+    /// Records the current state of the backend in its history.
     fn flush(&mut self) -> Result<(), io::Error> {
+        if self.history_capacity == 0 {
+            return Ok(());
+        }
+        if self.history.len() == self.history_capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(TestFrame {
+            buffer: self.buffer.clone(),
+            cursor_position: self.pos,
+            cursor_visible: self.cursor,
+        });
         Ok(())
     }
 }
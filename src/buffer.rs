@@ -3,26 +3,40 @@ use std::{
     fmt::{Debug, Formatter, Result},
 };
 
+use compact_str::CompactString;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 #[allow(deprecated)]
 use crate::{
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::{Line, Span, Spans},
+    text::{DisplayWidth, Line, Span, Spans},
 };
 
+mod composite;
+mod export;
+mod region;
+mod snapshot;
+pub use composite::{BlitOptions, Shadow};
+pub use snapshot::{assert_snapshot_file, ParseSnapshotError, UPDATE_SNAPSHOTS_ENV};
+
 /// A buffer cell
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cell {
-    pub symbol: String,
+    /// The grapheme displayed in the cell. Symbols of up to 24 bytes, which covers virtually all
+    /// graphemes, are stored inline without any heap allocation.
+    symbol: CompactString,
     pub fg: Color,
     pub bg: Color,
     pub modifier: Modifier,
 }
 
 impl Cell {
+    /// Returns the grapheme displayed in the cell.
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_str()
+    }
+
     pub fn set_symbol(&mut self, symbol: &str) -> &mut Cell {
         self.symbol.clear();
         self.symbol.push_str(symbol);
@@ -64,6 +78,15 @@ impl Cell {
             .add_modifier(self.modifier)
     }
 
+    /// Returns the display width of the symbol, without looking up the unicode tables for
+    /// printable ASCII symbols.
+    pub(crate) fn symbol_width(&self) -> usize {
+        match self.symbol.as_bytes() {
+            [b' '..=b'~'] => 1,
+            _ => self.symbol.width(),
+        }
+    }
+
     pub fn reset(&mut self) {
         self.symbol.clear();
         self.symbol.push(' ');
@@ -76,7 +99,7 @@ impl Cell {
 impl Default for Cell {
     fn default() -> Cell {
         Cell {
-            symbol: " ".into(),
+            symbol: CompactString::new_inline(" "),
             fg: Color::Reset,
             bg: Color::Reset,
             modifier: Modifier::empty(),
@@ -84,6 +107,66 @@ impl Default for Cell {
     }
 }
 
+/// A run of updates produced by [`Buffer::diff_segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSegment<'a> {
+    /// Cells to draw one after the other on the same row, starting at (x, y). Each cell starts
+    /// where the previous one ends, which is not always the next column for multi-width symbols.
+    Cells {
+        x: u16,
+        y: u16,
+        cells: Vec<&'a Cell>,
+    },
+    /// Blank cells, all equal to `cell`, from (x, y) to the end of the row of the buffer. The
+    /// segment spans `width` cells, some of which may already be up to date, so that backends can
+    /// replace it with a single "clear until new line" operation using the background of `cell`.
+    ClearToEndOfLine {
+        x: u16,
+        y: u16,
+        width: u16,
+        cell: &'a Cell,
+    },
+}
+
+impl<'a> DiffSegment<'a> {
+    /// Returns the position and cell of every update in this segment, as expected by
+    /// [`Backend::draw`](crate::backend::Backend::draw).
+    pub fn into_cells(self) -> Vec<(u16, u16, &'a Cell)> {
+        match self {
+            DiffSegment::Cells { mut x, y, cells } => cells
+                .into_iter()
+                .map(|cell| {
+                    let position = (x, y, cell);
+                    x = x.saturating_add(cell.symbol_width().max(1) as u16);
+                    position
+                })
+                .collect(),
+            DiffSegment::ClearToEndOfLine { x, y, width, cell } => {
+                (x..x.saturating_add(width)).map(|x| (x, y, cell)).collect()
+            }
+        }
+    }
+
+    /// Returns the position and cell of the last update in this segment, i.e. the last item of
+    /// [`DiffSegment::into_cells`], without collecting the others.
+    pub fn last_cell(&self) -> Option<(u16, u16, &'a Cell)> {
+        match self {
+            DiffSegment::Cells { x, y, cells } => {
+                let (last, previous) = cells.split_last()?;
+                let offset = previous
+                    .iter()
+                    .map(|cell| cell.symbol_width().max(1) as u16)
+                    .fold(*x, u16::saturating_add);
+                Some((offset, *y, *last))
+            }
+            DiffSegment::ClearToEndOfLine { x, y, width, cell } => {
+                let end = x.saturating_add(*width);
+                (end > *x).then(|| (end - 1, *y, *cell))
+            }
+        }
+    }
+}
+
 /// A buffer that maps to the desired content of the terminal after the draw call
 ///
 /// No widget in the library interacts directly with the terminal. Instead each of them is required
@@ -96,30 +179,47 @@ impl Default for Cell {
 /// ```
 /// use ratatui::buffer::{Buffer, Cell};
 /// use ratatui::layout::Rect;
-/// use ratatui::style::{Color, Style, Modifier};
+/// use ratatui::style::{Color, Style};
 ///
 /// let mut buf = Buffer::empty(Rect{x: 0, y: 0, width: 10, height: 5});
 /// buf.get_mut(0, 2).set_symbol("x");
-/// assert_eq!(buf.get(0, 2).symbol, "x");
+/// assert_eq!(buf.get(0, 2).symbol(), "x");
 /// buf.set_string(3, 0, "string", Style::default().fg(Color::Red).bg(Color::White));
-/// assert_eq!(buf.get(5, 0), &Cell{
-///     symbol: String::from("r"),
-///     fg: Color::Red,
-///     bg: Color::White,
-///     modifier: Modifier::empty()
-/// });
+/// let mut expected = Cell::default();
+/// expected.set_symbol("r").set_fg(Color::Red).set_bg(Color::White);
+/// assert_eq!(buf.get(5, 0), &expected);
 /// buf.get_mut(5, 0).set_char('x');
-/// assert_eq!(buf.get(5, 0).symbol, "x");
+/// assert_eq!(buf.get(5, 0).symbol(), "x");
 /// ```
-#[derive(Clone, PartialEq, Eq, Default)]
+///
+/// # Dirty rows
+///
+/// The buffer keeps track of the rows modified since it was created empty or last [reset]. Rows
+/// that are untouched in two buffers only contain default cells, so [`Buffer::diff`] skips them
+/// without comparing their cells, and [`Buffer::reset`] only has to reset the modified rows. The
+/// cells can only be modified through the methods of `Buffer`, which all keep track of the rows
+/// they modify.
+///
+/// [reset]: Buffer::reset
+#[derive(Clone, Default)]
 pub struct Buffer {
     /// The area represented by this buffer
     pub area: Rect,
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
-    pub content: Vec<Cell>,
+    content: Vec<Cell>,
+    /// Whether each row may contain non default cells
+    dirty_rows: Vec<bool>,
+}
+
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Buffer) -> bool {
+        self.area == other.area && self.content == other.content
+    }
 }
 
+impl Eq for Buffer {}
+
 impl Buffer {
     /// Returns a Buffer with all cells set to the default one
     pub fn empty(area: Rect) -> Buffer {
@@ -134,7 +234,12 @@ impl Buffer {
         for _ in 0..size {
             content.push(cell.clone());
         }
-        Buffer { area, content }
+        let dirty_rows = vec![*cell != Cell::default(); area.height as usize];
+        Buffer {
+            area,
+            content,
+            dirty_rows,
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -165,6 +270,87 @@ impl Buffer {
         &self.content
     }
 
+    /// Returns the content of the buffer as a mutable slice.
+    ///
+    /// All the rows are considered modified, see the [dirty rows](Buffer#dirty-rows).
+    pub fn content_mut(&mut self) -> &mut [Cell] {
+        self.dirty_rows.fill(true);
+        &mut self.content
+    }
+
+    /// Returns whether the row at the given (global) coordinate may have been modified since the
+    /// buffer was created empty or last reset. Rows outside of the buffer are never dirty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::style::Style;
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+    /// buffer.set_string(0, 1, "Hi", Style::default());
+    /// assert!(!buffer.is_row_dirty(0));
+    /// assert!(buffer.is_row_dirty(1));
+    /// buffer.reset();
+    /// assert!(!buffer.is_row_dirty(1));
+    /// ```
+    pub fn is_row_dirty(&self, y: u16) -> bool {
+        y.checked_sub(self.area.y)
+            .and_then(|row| self.dirty_rows.get(row as usize))
+            .map_or(false, |dirty| *dirty)
+    }
+
+    fn mark_row_dirty(&mut self, y: u16) {
+        self.dirty_rows[(y - self.area.y) as usize] = true;
+    }
+
+    /// Replaces the multi-width symbol hiding the cell at the given coordinates with a blank, as
+    /// the cell is about to be overwritten.
+    fn clear_split_symbol(&mut self, x: u16, y: u16) {
+        if x > self.area.left() && self.get(x - 1, y).symbol_width() > 1 {
+            self.get_mut(x - 1, y).set_symbol(" ");
+        }
+    }
+
+    /// Repairs the multi-width symbols whose hidden cells have been overwritten, e.g. through
+    /// [`Buffer::get_mut`], by replacing them with blanks. Multi-width symbols overflowing the
+    /// right edge of the buffer are replaced with blanks too.
+    ///
+    /// Only the [dirty rows](Buffer#dirty-rows) are checked. [`Terminal`] calls this method before
+    /// drawing each frame, so that the terminal never receives a partially overwritten symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// let mut buffer = Buffer::with_lines(vec!["称号"]);
+    /// buffer.get_mut(1, 0).set_symbol("a");
+    /// buffer.repair_wide_symbols();
+    /// assert_eq!(buffer, Buffer::with_lines(vec![" a号"]));
+    /// ```
+    ///
+    /// [`Terminal`]: crate::Terminal
+    pub fn repair_wide_symbols(&mut self) {
+        let width = self.area.width as usize;
+        if width == 0 {
+            return;
+        }
+        let rows = self.content.chunks_mut(width).zip(&self.dirty_rows);
+        for row in rows.filter(|(_, &dirty)| dirty).map(|(row, _)| row) {
+            let mut i = 0;
+            while i < width {
+                let symbol_width = row[i].symbol_width();
+                let hidden = i + 1..i + symbol_width;
+                if hidden.end > width || row[hidden].iter().any(|cell| cell.symbol != " ") {
+                    row[i].set_symbol(" ");
+                    i += 1;
+                } else {
+                    i += symbol_width.max(1);
+                }
+            }
+        }
+    }
+
     /// Returns the area covered by this buffer
     pub fn area(&self) -> &Rect {
         &self.area
@@ -179,6 +365,7 @@ impl Buffer {
     /// Returns a mutable reference to Cell at the given coordinates
     pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
         let i = self.index_of(x, y);
+        self.mark_row_dirty(y);
         &mut self.content[i]
     }
 
@@ -283,6 +470,7 @@ impl Buffer {
         S: AsRef<str>,
     {
         let mut index = self.index_of(x, y);
+        self.mark_row_dirty(y);
         let mut x_offset = x as usize;
         let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true);
         let max_offset = min(self.area.right() as usize, width.saturating_add(x as usize));
@@ -296,6 +484,10 @@ impl Buffer {
             if width > max_offset.saturating_sub(x_offset) {
                 break;
             }
+            if x_offset == x as usize {
+                // the first cell may be hidden by a multi-width symbol
+                self.clear_split_symbol(x, y);
+            }
 
             self.content[index].set_symbol(s);
             self.content[index].set_style(style);
@@ -386,13 +578,27 @@ impl Buffer {
         } else {
             self.content.resize(length, Cell::default());
         }
+        if area != self.area {
+            // the existing cells may have moved to other rows
+            self.dirty_rows = vec![true; area.height as usize];
+        }
         self.area = area;
     }
 
     /// Reset all cells in the buffer
     pub fn reset(&mut self) {
-        for c in &mut self.content {
-            c.reset();
+        let width = self.area.width as usize;
+        for (row, dirty) in self
+            .content
+            .chunks_mut(width.max(1))
+            .zip(&mut self.dirty_rows)
+        {
+            if *dirty {
+                for c in row {
+                    c.reset();
+                }
+                *dirty = false;
+            }
         }
     }
 
@@ -424,6 +630,18 @@ impl Buffer {
             self.content[k] = other.content[i].clone();
         }
         self.area = area;
+        self.dirty_rows = vec![true; area.height as usize];
+
+        // Repair the multi-width symbols split by the edges of the other buffer
+        for y in other.area.top()..other.area.bottom() {
+            self.clear_split_symbol(other.area.left(), y);
+            if other.area.right() < area.right() {
+                let last = other.get(other.area.right() - 1, y);
+                if last.symbol_width() > 1 {
+                    self.get_mut(other.area.right(), y).set_symbol(" ");
+                }
+            }
+        }
     }
 
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
@@ -457,6 +675,8 @@ impl Buffer {
     pub fn diff<'a>(&self, other: &'a Buffer) -> Vec<(u16, u16, &'a Cell)> {
         let previous_buffer = &self.content;
         let next_buffer = &other.content;
+        let width = (self.area.width as usize).max(1);
+        let same_area = self.area == other.area;
 
         let mut updates: Vec<(u16, u16, &Cell)> = vec![];
         // Cells invalidated by drawing/replacing preceding multi-width characters:
@@ -464,19 +684,118 @@ impl Buffer {
         // Cells from the current buffer to skip due to preceding multi-width characters taking
         // their place (the skipped cells should be blank anyway):
         let mut to_skip: usize = 0;
-        for (i, (current, previous)) in next_buffer.iter().zip(previous_buffer.iter()).enumerate() {
-            if (current != previous || invalidated > 0) && to_skip == 0 {
-                let (x, y) = self.pos_of(i);
-                updates.push((x, y, &next_buffer[i]));
+        let rows = next_buffer.chunks(width).zip(previous_buffer.chunks(width));
+        for (row, (next_row, previous_row)) in rows.enumerate() {
+            if same_area && invalidated == 0 && to_skip == 0 {
+                // Rows untouched in both buffers only contain default cells
+                if !self.dirty_rows[row] && !other.dirty_rows[row] {
+                    continue;
+                }
+                if next_row == previous_row {
+                    let last_width = next_row.last().map_or(0, Cell::symbol_width);
+                    to_skip = last_width.saturating_sub(1);
+                    invalidated = to_skip;
+                    continue;
+                }
             }
+            for (col, (current, previous)) in next_row.iter().zip(previous_row).enumerate() {
+                if (current != previous || invalidated > 0) && to_skip == 0 {
+                    let (x, y) = self.pos_of(row * width + col);
+                    updates.push((x, y, current));
+                }
 
-            to_skip = current.symbol.width().saturating_sub(1);
+                let current_width = current.symbol_width();
+                to_skip = current_width.saturating_sub(1);
 
-            let affected_width = std::cmp::max(current.symbol.width(), previous.symbol.width());
-            invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+                let affected_width = std::cmp::max(current_width, previous.symbol_width());
+                invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
+            }
         }
         updates
     }
+
+    /// Builds the same updates as [`Buffer::diff`], grouped into segments.
+    ///
+    /// Updates of consecutive cells on a row are gathered into [`DiffSegment::Cells`]. When the
+    /// end of a row of `other` is made of identical blank cells and some of them changed, they are
+    /// replaced by a single [`DiffSegment::ClearToEndOfLine`] starting at the first changed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::{Buffer, Cell, DiffSegment};
+    /// let previous = Buffer::with_lines(vec!["abcdef"]);
+    /// let next = Buffer::with_lines(vec!["aXc   "]);
+    /// let blank = Cell::default();
+    /// assert_eq!(
+    ///     previous.diff_segments(&next),
+    ///     vec![
+    ///         DiffSegment::Cells { x: 1, y: 0, cells: vec![next.get(1, 0)] },
+    ///         DiffSegment::ClearToEndOfLine { x: 3, y: 0, width: 3, cell: &blank },
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff_segments<'a>(&self, other: &'a Buffer) -> Vec<DiffSegment<'a>> {
+        let mut segments = vec![];
+        // (row, first column of the trailing blank cells of that row in `other`)
+        let mut blank_start: Option<(u16, u16)> = None;
+        let mut cleared_row = None;
+        // (row, column) right after the last cell of the current `DiffSegment::Cells`
+        let mut run_end = None;
+        for (x, y, cell) in self.diff(other) {
+            if cleared_row == Some(y) {
+                continue;
+            }
+            let start = match blank_start {
+                Some((row, start)) if row == y => start,
+                _ => {
+                    let start = other.trailing_blank_start(y);
+                    blank_start = Some((y, start));
+                    start
+                }
+            };
+            if x >= start {
+                segments.push(DiffSegment::ClearToEndOfLine {
+                    x,
+                    y,
+                    width: other.area.right() - x,
+                    cell,
+                });
+                cleared_row = Some(y);
+                continue;
+            }
+            match segments.last_mut() {
+                Some(DiffSegment::Cells { cells, .. }) if run_end == Some((y, x)) => {
+                    cells.push(cell)
+                }
+                _ => segments.push(DiffSegment::Cells {
+                    x,
+                    y,
+                    cells: vec![cell],
+                }),
+            }
+            run_end = Some((y, x.saturating_add(cell.symbol_width().max(1) as u16)));
+        }
+        segments
+    }
+
+    /// Returns the first column of the run of identical blank cells ending the given row, or the
+    /// right edge of the buffer if the row does not end with a blank cell.
+    fn trailing_blank_start(&self, y: u16) -> u16 {
+        let width = self.area.width as usize;
+        let start = self.index_of(self.area.x, y);
+        let row = &self.content[start..start + width];
+        let Some(last) = row.last().filter(|c| c.symbol == " ") else {
+            return self.area.right();
+        };
+        let mut blank = row.iter().rposition(|c| c != last).map_or(0, |i| i + 1);
+        // a multi-width symbol before the blank run hides its first cells, which must not be
+        // cleared
+        for (i, cell) in row[..blank].iter().enumerate() {
+            blank = blank.max(i + cell.symbol_width());
+        }
+        self.area.x + blank.min(width) as u16
+    }
 }
 
 /// Assert that two buffers are equal by comparing their areas and content.
@@ -594,6 +913,15 @@ mod tests {
         cell
     }
 
+    #[test]
+    fn cell_symbol_width() {
+        assert_eq!(cell("a").symbol_width(), 1);
+        assert_eq!(cell("称").symbol_width(), 2);
+        assert_eq!(cell("\u{200b}").symbol_width(), 0);
+        assert_eq!(cell("\t").symbol_width(), "\t".width());
+        assert_eq!(cell("").symbol_width(), 0);
+    }
+
     #[test]
     fn it_implements_debug() {
         let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
@@ -854,6 +1182,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn buffer_diff_segments_groups_consecutive_cells() {
+        let prev = Buffer::with_lines(vec!["┌Title─┐  ", "└──────┘  "]);
+        let next = Buffer::with_lines(vec!["┌TITLE─┐  ", "└─称号─┘  "]);
+        let diff = prev.diff_segments(&next);
+        assert_eq!(
+            diff,
+            vec![
+                DiffSegment::Cells {
+                    x: 2,
+                    y: 0,
+                    cells: vec![&cell("I"), &cell("T"), &cell("L"), &cell("E")],
+                },
+                DiffSegment::Cells {
+                    x: 2,
+                    y: 1,
+                    cells: vec![&cell("称"), &cell("号")],
+                },
+            ]
+        );
+        for segment in &diff {
+            assert_eq!(segment.last_cell(), segment.clone().into_cells().pop());
+        }
+        let cells: Vec<_> = diff.into_iter().flat_map(DiffSegment::into_cells).collect();
+        assert_eq!(cells, prev.diff(&next));
+    }
+
+    #[test]
+    fn buffer_diff_segments_clears_trailing_blanks() {
+        let prev = Buffer::with_lines(vec!["abc   def ", "abcdefghij"]);
+        let mut next = Buffer::with_lines(vec!["aXc       ", "称        "]);
+        next.set_style(Rect::new(3, 0, 7, 1), Style::default().bg(Color::Blue));
+        let mut blue = Cell::default();
+        blue.set_bg(Color::Blue);
+        let diff = prev.diff_segments(&next);
+        for segment in &diff {
+            assert_eq!(segment.last_cell(), segment.clone().into_cells().pop());
+        }
+        assert_eq!(
+            diff,
+            vec![
+                DiffSegment::Cells {
+                    x: 1,
+                    y: 0,
+                    cells: vec![&cell("X")],
+                },
+                DiffSegment::ClearToEndOfLine {
+                    x: 3,
+                    y: 0,
+                    width: 7,
+                    cell: &blue,
+                },
+                DiffSegment::Cells {
+                    x: 0,
+                    y: 1,
+                    cells: vec![&cell("称")],
+                },
+                // the cell hidden by the double width symbol is not cleared
+                DiffSegment::ClearToEndOfLine {
+                    x: 2,
+                    y: 1,
+                    width: 8,
+                    cell: &Cell::default(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn buffer_merge() {
         let mut one = Buffer::filled(
@@ -935,4 +1331,129 @@ mod tests {
         };
         assert_buffer_eq!(one, merged);
     }
+
+    #[test]
+    fn buffer_tracks_dirty_rows() {
+        let area = Rect::new(0, 0, 3, 4);
+        let mut buffer = Buffer::empty(area);
+        assert!((0..4).all(|y| !buffer.is_row_dirty(y)));
+
+        buffer.set_string(0, 0, "a", Style::default());
+        buffer.get_mut(1, 1).set_char('b');
+        buffer.set_style(Rect::new(0, 2, 1, 1), Style::default().fg(Color::Red));
+        assert_eq!(
+            (0..4).map(|y| buffer.is_row_dirty(y)).collect::<Vec<_>>(),
+            [true, true, true, false]
+        );
+
+        buffer.reset();
+        assert!((0..4).all(|y| !buffer.is_row_dirty(y)));
+        assert_eq!(buffer, Buffer::empty(area));
+
+        buffer.content_mut()[0].set_char('c');
+        assert!((0..4).all(|y| buffer.is_row_dirty(y)));
+        assert!(Buffer::filled(area, Cell::default().set_char('x')).is_row_dirty(0));
+
+        // rows outside of the buffer
+        let buffer = Buffer::filled(Rect::new(0, 2, 3, 1), Cell::default().set_char('x'));
+        assert!(buffer.is_row_dirty(2));
+        assert!(!buffer.is_row_dirty(0));
+        assert!(!buffer.is_row_dirty(3));
+    }
+
+    #[test]
+    fn buffer_reset_resets_rows_written_through_content_mut() {
+        let mut buffer = Buffer::with_lines(vec!["ab", "cd"]);
+        buffer.reset();
+        buffer.content_mut()[0].set_char('x');
+        buffer.reset();
+        assert_eq!(buffer, Buffer::empty(buffer.area));
+    }
+
+    #[test]
+    fn buffer_diff_skips_clean_and_equal_rows() {
+        let area = Rect::new(0, 0, 3, 3);
+        let mut previous = Buffer::empty(area);
+        previous.set_string(0, 1, "abc", Style::default());
+        previous.set_string(0, 2, "称c", Style::default());
+        let mut next = previous.clone();
+        next.get_mut(2, 2).set_char('d');
+        let diff = previous.diff(&next);
+        assert_eq!(diff, vec![(2, 2, next.get(2, 2))]);
+
+        // writes through the content are seen by the diff
+        next.content_mut()[0].set_char('x');
+        assert_eq!(previous.diff(&next).len(), 2);
+    }
+
+    #[test]
+    fn buffer_set_string_over_wide_symbols() {
+        // narrow symbol over the hidden half of a wide symbol
+        let mut buffer = Buffer::with_lines(vec!["称号"]);
+        buffer.set_string(1, 0, "a", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(vec![" a号"]));
+
+        // narrow symbol over the first half of a wide symbol
+        let mut buffer = Buffer::with_lines(vec!["称号"]);
+        buffer.set_string(2, 0, "b", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(vec!["称b "]));
+
+        // wide symbol straddling two wide symbols
+        let mut buffer = Buffer::with_lines(vec!["😀😀"]);
+        buffer.set_string(1, 0, "称", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(vec![" 称 "]));
+
+        // wide symbol not fitting at the right edge
+        let mut buffer = Buffer::with_lines(vec!["abc"]);
+        buffer.set_string(2, 0, "😀", Style::default());
+        assert_eq!(buffer, Buffer::with_lines(vec!["abc"]));
+
+        // wide symbol at the left edge of a buffer not starting at 0
+        let mut buffer = Buffer::empty(Rect::new(1, 0, 3, 1));
+        buffer.set_string(1, 0, "称", Style::default());
+        buffer.set_string(2, 0, "ab", Style::default());
+        let mut expected = Buffer::empty(Rect::new(1, 0, 3, 1));
+        expected.set_string(1, 0, " ab", Style::default());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn buffer_repair_wide_symbols() {
+        let mut buffer = Buffer::with_lines(vec!["称号😀", "称号😀"]);
+        buffer.reset();
+        buffer.set_string(0, 0, "称号😀", Style::default());
+        buffer.set_string(0, 1, "称号😀", Style::default());
+        buffer.get_mut(1, 0).set_symbol("a");
+        buffer.get_mut(5, 1).set_symbol("b");
+        // styling hidden cells keeps the wide symbols
+        buffer.get_mut(3, 0).set_fg(Color::Red);
+        buffer.repair_wide_symbols();
+
+        let mut expected = Buffer::with_lines(vec![" a号😀", "称号 b"]);
+        expected.get_mut(3, 0).set_fg(Color::Red);
+        assert_eq!(buffer, expected);
+
+        // a wide symbol overflowing the right edge is blanked
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.get_mut(2, 0).set_symbol("称");
+        buffer.repair_wide_symbols();
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 3, 1)));
+    }
+
+    #[test]
+    fn buffer_merge_splitting_wide_symbols() {
+        let mut one = Buffer::with_lines(vec!["称号😀"]);
+        let mut two = Buffer::empty(Rect::new(1, 0, 2, 1));
+        two.set_string(1, 0, "ab", Style::default());
+        one.merge(&two);
+        assert_eq!(one, Buffer::with_lines(vec![" ab 😀"]));
+
+        // the last symbol of the other buffer overflows its right edge
+        let mut one = Buffer::with_lines(vec!["abcdef"]);
+        let mut two = Buffer::empty(Rect::new(1, 0, 2, 1));
+        two.get_mut(1, 0).set_char('x');
+        two.get_mut(2, 0).set_symbol("😀");
+        one.merge(&two);
+        assert_eq!(one, Buffer::with_lines(vec!["ax😀ef"]));
+    }
 }
@@ -0,0 +1,329 @@
+//! Compositing of a [`Buffer`] on top of another one.
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::DisplayWidth,
+};
+
+/// A drop shadow cast by a buffer composited with [`Buffer::blit`].
+///
+/// The shadow covers the area of the composited buffer moved by the given offset. Only the cells
+/// beneath that remain visible are affected, their symbols are kept and the style is patched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shadow {
+    /// Horizontal offset of the shadow, in cells
+    pub offset_x: u16,
+    /// Vertical offset of the shadow, in cells
+    pub offset_y: u16,
+    /// Style applied to the cells in the shadow
+    pub style: Style,
+}
+
+/// Options controlling how [`Buffer::blit`] composites a buffer onto another.
+///
+/// The default options copy every cell as is, like [`Buffer::merge`] does.
+///
+/// # Examples
+///
+/// A modal dialog dimming the rest of the screen and casting a shadow:
+///
+/// ```
+/// # use ratatui::buffer::{BlitOptions, Shadow};
+/// # use ratatui::style::{Color, Modifier, Style};
+/// let options = BlitOptions {
+///     backdrop: Some(Style::default().add_modifier(Modifier::DIM)),
+///     shadow: Some(Shadow {
+///         offset_x: 2,
+///         offset_y: 1,
+///         style: Style::default().bg(Color::Black),
+///     }),
+///     ..BlitOptions::default()
+/// };
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlitOptions {
+    /// Keep the background color of the cells beneath
+    pub keep_background: bool,
+    /// Treat [`Color::Reset`] as transparent: a reset foreground or background color keeps the
+    /// color of the cell beneath, and blank cells with a reset background and no modifier let
+    /// the cell beneath show through entirely
+    pub transparent_reset: bool,
+    /// Style applied to the whole destination buffer before compositing, e.g. to dim what is
+    /// behind a popup
+    pub backdrop: Option<Style>,
+    /// Shadow cast by the composited buffer onto the cells beneath
+    pub shadow: Option<Shadow>,
+}
+
+impl BlitOptions {
+    /// Returns whether the given source cell lets the cell beneath show through
+    fn is_transparent(&self, cell: &Cell) -> bool {
+        self.transparent_reset
+            && cell.symbol == " "
+            && cell.bg == Color::Reset
+            && cell.modifier == Modifier::empty()
+    }
+
+    /// Combines a source cell with the cell beneath it
+    fn composite(&self, source: &Cell, beneath: &mut Cell, symbol: &str) {
+        beneath.set_symbol(symbol);
+        if !(self.transparent_reset && source.fg == Color::Reset) {
+            beneath.fg = source.fg;
+        }
+        if !(self.keep_background || self.transparent_reset && source.bg == Color::Reset) {
+            beneath.bg = source.bg;
+        }
+        beneath.modifier = source.modifier;
+    }
+}
+
+impl Buffer {
+    /// Composites `source` onto this buffer with its top left corner at the global coordinates
+    /// `(x, y)`.
+    ///
+    /// Unlike [`Buffer::merge`], the area of this buffer is never extended: the parts of `source`
+    /// falling outside of it are clipped. The area of `source` is only used for its size, so a
+    /// widget can be rendered offscreen into a buffer starting at `(0, 0)` and composited anywhere.
+    ///
+    /// Multi-width symbols are kept well-formed: a symbol of this buffer partially covered by
+    /// `source` is erased, and a symbol of `source` that does not fit in this buffer is replaced
+    /// with a blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::{BlitOptions, Buffer, Cell};
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::style::{Color, Style};
+    /// let mut buffer = Buffer::filled(
+    ///     Rect::new(0, 0, 5, 1),
+    ///     Cell::default().set_symbol("x").set_bg(Color::Blue),
+    /// );
+    /// let popup = Buffer::with_lines(vec!["a b"]);
+    /// let options = BlitOptions {
+    ///     transparent_reset: true,
+    ///     ..BlitOptions::default()
+    /// };
+    /// buffer.blit(&popup, 1, 0, options);
+    ///
+    /// let mut expected = Buffer::with_lines(vec!["xaxbx"]);
+    /// expected.set_style(*expected.area(), Style::default().bg(Color::Blue));
+    /// assert_eq!(buffer, expected);
+    /// ```
+    pub fn blit(&mut self, source: &Buffer, x: u16, y: u16, options: BlitOptions) {
+        self.blit_clipped(source, x, y, self.area, options);
+    }
+
+    /// Composites `source` like [`Buffer::blit`] but only updates the cells inside `clip`.
+    pub(crate) fn blit_clipped(
+        &mut self,
+        source: &Buffer,
+        x: u16,
+        y: u16,
+        clip: Rect,
+        options: BlitOptions,
+    ) {
+        if !clip.intersects(self.area) {
+            return;
+        }
+        let clip = clip.intersection(self.area);
+        if let Some(style) = options.backdrop {
+            self.set_style(clip, style);
+        }
+        if let Some(shadow) = options.shadow {
+            let area = clamped_area(
+                x.saturating_add(shadow.offset_x),
+                y.saturating_add(shadow.offset_y),
+                source.area,
+            );
+            if area.intersects(clip) {
+                self.set_style(area.intersection(clip), shadow.style);
+            }
+        }
+
+        let target = clamped_area(x, y, source.area);
+        if !target.intersects(clip) {
+            return;
+        }
+        let target = target.intersection(clip);
+        for row in 0..source.area.height {
+            // the rows past the last coordinate are out of any area
+            let Some(dy) = y.checked_add(row) else {
+                break;
+            };
+            if dy < target.top() || dy >= target.bottom() {
+                continue;
+            }
+            // Columns of `source` hidden by a preceding multi-width symbol
+            let mut hidden: usize = 0;
+            for col in 0..source.area.width {
+                let Some(dx) = x.checked_add(col) else {
+                    break;
+                };
+                let cell = source.get(source.area.x + col, source.area.y + row);
+                let width = cell.symbol.width();
+                let is_hidden = hidden > 0;
+                hidden = hidden.max(width).saturating_sub(1);
+                if dx < target.left() || dx >= target.right() {
+                    continue;
+                }
+                if !is_hidden && options.is_transparent(cell) {
+                    continue;
+                }
+                self.clear_split_symbol(dx, dy);
+                let symbol = if is_hidden || dx as usize + width > target.right() as usize {
+                    " "
+                } else {
+                    cell.symbol.as_str()
+                };
+                options.composite(cell, self.get_mut(dx, dy), symbol);
+            }
+        }
+    }
+}
+
+/// Returns the area of the given size at (x, y), without the part past the last coordinate
+fn clamped_area(x: u16, y: u16, size: Rect) -> Rect {
+    Rect {
+        x,
+        y,
+        width: size.width.min(u16::MAX - x),
+        height: size.height.min(u16::MAX - y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> BlitOptions {
+        BlitOptions::default()
+    }
+
+    #[test]
+    fn blit_clips_to_area() {
+        let mut buffer = Buffer::with_lines(vec!["....", "...."]);
+        let source = Buffer::with_lines(vec!["ab", "cd"]);
+        buffer.blit(&source, 3, 1, options());
+        assert_eq!(buffer, Buffer::with_lines(vec!["....", "...a"]));
+    }
+
+    #[test]
+    fn blit_near_the_last_coordinate() {
+        let mut buffer = Buffer::empty(Rect::new(u16::MAX - 2, u16::MAX - 1, 2, 1));
+        let source = Buffer::with_lines(vec!["abcd", "efgh", "ijkl"]);
+        buffer.blit(&source, u16::MAX - 2, u16::MAX - 1, options());
+        assert_eq!(buffer.to_lines(false), vec!["ab"]);
+    }
+
+    #[test]
+    fn blit_ignores_source_position() {
+        let mut buffer = Buffer::with_lines(vec!["...."]);
+        let mut source = Buffer::empty(Rect::new(10, 10, 2, 1));
+        source.set_string(10, 10, "ab", Style::default());
+        buffer.blit(&source, 1, 0, options());
+        assert_eq!(buffer, Buffer::with_lines(vec![".ab."]));
+    }
+
+    #[test]
+    fn blit_keeps_background() {
+        let mut buffer = Buffer::with_lines(vec!["...."]);
+        buffer.set_style(buffer.area, Style::default().bg(Color::Blue));
+        let mut source = Buffer::with_lines(vec!["ab"]);
+        source.set_style(
+            source.area,
+            Style::default().fg(Color::Red).bg(Color::Green),
+        );
+        buffer.blit(
+            &source,
+            0,
+            0,
+            BlitOptions {
+                keep_background: true,
+                ..options()
+            },
+        );
+
+        let mut expected = Buffer::with_lines(vec!["ab.."]);
+        expected.set_style(expected.area, Style::default().bg(Color::Blue));
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Red));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn blit_transparent_reset() {
+        let mut buffer = Buffer::with_lines(vec!["...."]);
+        buffer.set_style(buffer.area, Style::default().fg(Color::Red).bg(Color::Blue));
+        let mut source = Buffer::with_lines(vec!["a  b"]);
+        source.set_style(Rect::new(2, 0, 1, 1), Style::default().bg(Color::Green));
+        buffer.blit(
+            &source,
+            0,
+            0,
+            BlitOptions {
+                transparent_reset: true,
+                ..options()
+            },
+        );
+
+        let mut expected = Buffer::with_lines(vec!["a. b"]);
+        expected.set_style(
+            expected.area,
+            Style::default().fg(Color::Red).bg(Color::Blue),
+        );
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::default().bg(Color::Green));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn blit_backdrop_and_shadow() {
+        let mut buffer = Buffer::with_lines(vec!["....", "....", "...."]);
+        let source = Buffer::with_lines(vec!["ab"]);
+        let shadow = Style::default().bg(Color::Black);
+        buffer.blit(
+            &source,
+            1,
+            1,
+            BlitOptions {
+                backdrop: Some(Style::default().add_modifier(Modifier::DIM)),
+                shadow: Some(Shadow {
+                    offset_x: 1,
+                    offset_y: 1,
+                    style: shadow,
+                }),
+                ..options()
+            },
+        );
+
+        let mut expected = Buffer::with_lines(vec!["....", ".ab.", "...."]);
+        expected.set_style(expected.area, Style::default().add_modifier(Modifier::DIM));
+        expected.set_style(Rect::new(2, 2, 2, 1), shadow);
+        expected.set_style(
+            Rect::new(1, 1, 2, 1),
+            Style::default().remove_modifier(Modifier::DIM),
+        );
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn blit_wide_symbols() {
+        // A wide symbol beneath partially covered on its right is erased
+        let mut buffer = Buffer::with_lines(vec!["称号.."]);
+        buffer.blit(&Buffer::with_lines(vec!["ab"]), 1, 0, options());
+        assert_eq!(buffer, Buffer::with_lines(vec![" ab .."]));
+
+        // A wide symbol of the source clipped on the right is replaced with a blank
+        let mut buffer = Buffer::with_lines(vec!["...."]);
+        buffer.blit(&Buffer::with_lines(vec!["a称"]), 2, 0, options());
+        assert_eq!(buffer, Buffer::with_lines(vec!["..a "]));
+
+        // A wide symbol of the source clipped on the left leaves a blank
+        let mut buffer = Buffer::filled(Rect::new(2, 0, 3, 1), Cell::default().set_symbol("."));
+        buffer.blit(&Buffer::with_lines(vec!["称ab"]), 1, 0, options());
+        let mut expected = Buffer::empty(Rect::new(2, 0, 3, 1));
+        expected.set_string(2, 0, " ab", Style::default());
+        assert_eq!(buffer, expected);
+    }
+}
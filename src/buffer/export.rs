@@ -0,0 +1,129 @@
+//! Export of a [`Buffer`] to plain or ANSI-styled text, e.g. to dump a screen to a log file.
+
+use crate::{
+    backend::reset_params,
+    buffer::{Buffer, Cell},
+};
+
+impl Buffer {
+    /// Returns the text of the buffer, one string per row.
+    ///
+    /// The cells hidden by multi-width symbols are skipped, so that each line is displayed as in
+    /// the buffer. When `trim_end` is `true`, the trailing spaces of each line are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// let buffer = Buffer::with_lines(vec!["称号 ab  ", "cd"]);
+    /// assert_eq!(buffer.to_lines(false), vec!["称号 ab  ", "cd       "]);
+    /// assert_eq!(buffer.to_lines(true), vec!["称号 ab", "cd"]);
+    /// ```
+    pub fn to_lines(&self, trim_end: bool) -> Vec<String> {
+        self.rows()
+            .map(|row| {
+                let mut line: String = visible_cells(row).map(|c| c.symbol.as_str()).collect();
+                if trim_end {
+                    line.truncate(line.trim_end_matches(' ').len());
+                }
+                line
+            })
+            .collect()
+    }
+
+    /// Returns the content of the buffer as a string of lines styled with ANSI escape sequences.
+    ///
+    /// Each line ends with a newline and resets the style if needed, so that the string can be
+    /// printed to a terminal or appended to a log file once the application has exited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::style::{Color, Style};
+    /// let mut buffer = Buffer::with_lines(vec!["Hi!", "..."]);
+    /// buffer.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Red));
+    /// assert_eq!(buffer.to_ansi_string(), "\x1b[0;31mHi\x1b[0m!\n...\n");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        let default = Cell::default();
+        let mut ansi = String::new();
+        for row in self.rows() {
+            let mut style = &default;
+            for cell in visible_cells(row) {
+                if !same_style(cell, style) {
+                    ansi.push_str("\x1b[");
+                    ansi.push_str(&reset_params(cell));
+                    ansi.push('m');
+                    style = cell;
+                }
+                ansi.push_str(&cell.symbol);
+            }
+            if !same_style(style, &default) {
+                ansi.push_str("\x1b[0m");
+            }
+            ansi.push('\n');
+        }
+        ansi
+    }
+
+    /// Returns an iterator over the rows of the buffer
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.content.chunks(self.area.width.max(1) as usize)
+    }
+}
+
+/// Returns the cells of a row that are not hidden by a preceding multi-width symbol
+fn visible_cells(row: &[Cell]) -> impl Iterator<Item = &Cell> {
+    let mut hidden: usize = 0;
+    row.iter().filter(move |cell| {
+        let is_hidden = hidden > 0;
+        hidden = if is_hidden {
+            hidden - 1
+        } else {
+            cell.symbol_width().saturating_sub(1)
+        };
+        !is_hidden
+    })
+}
+
+fn same_style(a: &Cell, b: &Cell) -> bool {
+    a.fg == b.fg && a.bg == b.bg && a.modifier == b.modifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        layout::Rect,
+        style::{Color, Modifier, Style},
+    };
+
+    #[test]
+    fn to_lines_skips_hidden_cells() {
+        let mut buffer = Buffer::with_lines(vec!["称号 ", "abcde"]);
+        // a leftover symbol in a hidden cell is not exported
+        buffer.get_mut(1, 0).set_symbol("x");
+        assert_eq!(buffer.to_lines(false), vec!["称号 ", "abcde"]);
+        assert_eq!(buffer.to_lines(true), vec!["称号", "abcde"]);
+    }
+
+    #[test]
+    fn to_lines_empty() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 0, 0));
+        assert!(buffer.to_lines(true).is_empty());
+    }
+
+    #[test]
+    fn to_ansi_string_merges_styles() {
+        let mut buffer = Buffer::with_lines(vec!["abcd", "称号"]);
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        buffer.set_style(Rect::new(1, 0, 2, 1), bold);
+        buffer.set_style(Rect::new(0, 1, 4, 1), Style::default().bg(Color::Blue));
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "a\x1b[0;1mbc\x1b[0md\n\x1b[0;44m称号\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! Raster operations on rectangular regions of a [`Buffer`].
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    text::DisplayWidth,
+};
+
+impl Buffer {
+    /// Copies the cells of `source` inside `area` into this buffer, with the top left corner of
+    /// `area` at the global coordinates `(x, y)`.
+    ///
+    /// `area` is clipped to the area of `source`, and the copy is clipped to the area of this
+    /// buffer. A multi-width symbol split by the edges of the region is replaced with a blank, as
+    /// is a symbol of this buffer whose right part is overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// let source = Buffer::with_lines(vec!["abc", "def"]);
+    /// let mut buffer = Buffer::with_lines(vec!["....", "...."]);
+    /// buffer.copy_region(&source, Rect::new(1, 0, 2, 2), 2, 0);
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["..bc", "..ef"]));
+    /// ```
+    pub fn copy_region(&mut self, source: &Buffer, area: Rect, x: u16, y: u16) {
+        let Some(area) = source.visible_area(area) else {
+            return;
+        };
+        let target = Rect {
+            x,
+            y,
+            width: area.width,
+            height: area.height,
+        };
+        let Some(target) = self.visible_area(target) else {
+            return;
+        };
+        let (dx, dy) = (target.x - x, target.y - y);
+        for row in 0..target.height {
+            let start = source.index_of(area.x + dx, area.y + dy + row);
+            let cells = &source.content[start..start + target.width as usize];
+            self.write_cells(target.x, target.y + row, cells);
+        }
+    }
+
+    /// Sets all the cells inside `area` to the given cell.
+    ///
+    /// A multi-width symbol is repeated every `width` cells, the cells it hides being blanks of
+    /// the same style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::{Buffer, Cell};
+    /// # use ratatui::layout::Rect;
+    /// let mut buffer = Buffer::with_lines(vec!["....", "...."]);
+    /// buffer.fill(Rect::new(1, 1, 2, 1), Cell::default().set_symbol("#"));
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["....", ".##."]));
+    /// ```
+    pub fn fill(&mut self, area: Rect, cell: &Cell) {
+        let Some(area) = self.visible_area(area) else {
+            return;
+        };
+        let width = cell.symbol.width().max(1);
+        let mut blank = cell.clone();
+        blank.set_symbol(" ");
+        let cells: Vec<Cell> = (0..area.width as usize)
+            .map(|i| if i % width == 0 { cell } else { &blank }.clone())
+            .collect();
+        for y in area.top()..area.bottom() {
+            self.write_cells(area.x, y, &cells);
+        }
+    }
+
+    /// Returns a new buffer containing the cells of this buffer inside `area`.
+    ///
+    /// The returned buffer keeps the coordinates of the cells: its area is the intersection of
+    /// `area` with the area of this buffer. A multi-width symbol split by the edges of `area` is
+    /// replaced with a blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// let buffer = Buffer::with_lines(vec!["abc", "def"]);
+    /// let cropped = buffer.crop(Rect::new(1, 1, 5, 5));
+    /// assert_eq!(cropped.area, Rect::new(1, 1, 2, 1));
+    /// assert_eq!(cropped.content(), Buffer::with_lines(vec!["ef"]).content());
+    /// ```
+    pub fn crop(&self, area: Rect) -> Buffer {
+        let Some(area) = self.visible_area(area) else {
+            return Buffer::empty(Rect {
+                width: 0,
+                height: 0,
+                ..area
+            });
+        };
+        let mut buffer = Buffer::empty(area);
+        buffer.copy_region(self, area, area.x, area.y);
+        buffer
+    }
+
+    /// Scrolls the content of `area` up by `n` rows. The rows scrolled out are lost and the rows
+    /// scrolled in at the bottom are filled with the given cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::{Buffer, Cell};
+    /// let mut buffer = Buffer::with_lines(vec!["a", "b", "c"]);
+    /// buffer.scroll_up(buffer.area, 1, &Cell::default());
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["b", "c", " "]));
+    /// ```
+    pub fn scroll_up(&mut self, area: Rect, n: u16, fill: &Cell) {
+        let Some(area) = self.visible_area(area) else {
+            return;
+        };
+        let n = n.min(area.height);
+        for y in area.top()..area.bottom() - n {
+            self.move_row_segment(area.x, y + n, area.width, area.x, y);
+        }
+        self.fill(Rect::new(area.x, area.bottom() - n, area.width, n), fill);
+    }
+
+    /// Scrolls the content of `area` down by `n` rows. The rows scrolled out are lost and the rows
+    /// scrolled in at the top are filled with the given cell.
+    pub fn scroll_down(&mut self, area: Rect, n: u16, fill: &Cell) {
+        let Some(area) = self.visible_area(area) else {
+            return;
+        };
+        let n = n.min(area.height);
+        for y in (area.top() + n..area.bottom()).rev() {
+            self.move_row_segment(area.x, y - n, area.width, area.x, y);
+        }
+        self.fill(Rect::new(area.x, area.y, area.width, n), fill);
+    }
+
+    /// Scrolls the content of `area` left by `n` columns. The columns scrolled out are lost and
+    /// the columns scrolled in on the right are filled with the given cell.
+    pub fn scroll_left(&mut self, area: Rect, n: u16, fill: &Cell) {
+        let Some(area) = self.visible_area(area) else {
+            return;
+        };
+        let n = n.min(area.width);
+        for y in area.top()..area.bottom() {
+            self.move_row_segment(area.x + n, y, area.width - n, area.x, y);
+        }
+        self.fill(Rect::new(area.right() - n, area.y, n, area.height), fill);
+    }
+
+    /// Scrolls the content of `area` right by `n` columns. The columns scrolled out are lost and
+    /// the columns scrolled in on the left are filled with the given cell.
+    pub fn scroll_right(&mut self, area: Rect, n: u16, fill: &Cell) {
+        let Some(area) = self.visible_area(area) else {
+            return;
+        };
+        let n = n.min(area.width);
+        for y in area.top()..area.bottom() {
+            self.move_row_segment(area.x, y, area.width - n, area.x + n, y);
+        }
+        self.fill(Rect::new(area.x, area.y, n, area.height), fill);
+    }
+
+    /// Returns the part of `area` inside this buffer, if it is not empty
+    fn visible_area(&self, area: Rect) -> Option<Rect> {
+        if !area.intersects(self.area) {
+            return None;
+        }
+        // `intersects` is true for an empty area, e.g. when this buffer has no columns
+        let area = area.intersection(self.area);
+        (area.width > 0 && area.height > 0).then_some(area)
+    }
+
+    /// Copies `width` cells of the row `from_y` starting at column `from_x` to the row `to_y`
+    /// starting at column `to_x`.
+    fn move_row_segment(&mut self, from_x: u16, from_y: u16, width: u16, to_x: u16, to_y: u16) {
+        if width == 0 {
+            return;
+        }
+        let start = self.index_of(from_x, from_y);
+        let cells = self.content[start..start + width as usize].to_vec();
+        self.write_cells(to_x, to_y, &cells);
+    }
+
+    /// Writes `cells` to the row `y` starting at column `x`, which must fit in the buffer.
+    ///
+    /// The written cells are kept well-formed: the cells hidden by a multi-width symbol are set
+    /// to blanks, and a multi-width symbol not fitting in the written cells is replaced with a
+    /// blank. A symbol of this buffer whose right part is overwritten is replaced with a blank
+    /// too.
+    fn write_cells(&mut self, x: u16, y: u16, cells: &[Cell]) {
+        if cells.is_empty() {
+            return;
+        }
+        self.clear_split_symbol(x, y);
+        self.mark_row_dirty(y);
+        let start = self.index_of(x, y);
+        let mut hidden: usize = 0;
+        for (i, cell) in cells.iter().enumerate() {
+            let target = &mut self.content[start + i];
+            target.clone_from(cell);
+            let width = cell.symbol.width();
+            if hidden > 0 || i + width > cells.len() {
+                target.set_symbol(" ");
+            }
+            hidden = hidden.max(width).saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Color, Style};
+
+    #[test]
+    fn copy_region_clips() {
+        let source = Buffer::with_lines(vec!["abc", "def"]);
+        let mut buffer = Buffer::with_lines(vec!["....", "...."]);
+        buffer.copy_region(&source, Rect::new(0, 0, 10, 10), 2, 1);
+        assert_eq!(buffer, Buffer::with_lines(vec!["....", "..ab"]));
+    }
+
+    #[test]
+    fn copy_region_wide_symbols() {
+        let source = Buffer::with_lines(vec!["称号a"]);
+        // the region starts in the middle of 称 and ends in the middle of 号
+        let mut buffer = Buffer::with_lines(vec!["称...."]);
+        buffer.copy_region(&source, Rect::new(1, 0, 2, 1), 1, 0);
+        assert_eq!(buffer, Buffer::with_lines(vec!["   ..."]));
+    }
+
+    #[test]
+    fn fill_wide_symbol() {
+        let mut buffer = Buffer::with_lines(vec!["....."]);
+        let style = Style::default().fg(Color::Red);
+        let mut cell = Cell::default();
+        cell.set_symbol("称").set_style(style);
+        buffer.fill(Rect::new(0, 0, 5, 1), &cell);
+
+        let mut expected = Buffer::with_lines(vec!["称称 "]);
+        expected.set_style(expected.area, style);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crop_outside() {
+        let buffer = Buffer::with_lines(vec!["abc"]);
+        let cropped = buffer.crop(Rect::new(5, 5, 2, 2));
+        assert_eq!(cropped.area.area(), 0);
+        assert!(cropped.content.is_empty());
+    }
+
+    #[test]
+    fn zero_width_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(4, 0, 0, 3));
+        let source = Buffer::with_lines(vec!["abc"]);
+        buffer.fill(Rect::new(2, 0, 5, 1), &Cell::default());
+        buffer.copy_region(&source, source.area, 2, 0);
+        buffer.scroll_up(Rect::new(2, 0, 5, 3), 1, &Cell::default());
+        buffer.scroll_right(Rect::new(2, 0, 5, 3), 1, &Cell::default());
+        assert_eq!(buffer.crop(Rect::new(2, 0, 5, 1)).area.area(), 0);
+        assert_eq!(buffer, Buffer::empty(Rect::new(4, 0, 0, 3)));
+    }
+
+    #[test]
+    fn crop_wide_symbols() {
+        let buffer = Buffer::with_lines(vec!["a称b"]);
+        let cropped = buffer.crop(Rect::new(0, 0, 2, 1));
+        assert_eq!(cropped, Buffer::with_lines(vec!["a "]));
+    }
+
+    #[test]
+    fn scroll_vertically() {
+        let fill = Cell::default().set_symbol("~").clone();
+        let mut buffer = Buffer::with_lines(vec!["abc", "def", "ghi", "jkl"]);
+        buffer.scroll_up(Rect::new(1, 0, 2, 4), 1, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["aef", "dhi", "gkl", "j~~"]));
+        buffer.scroll_down(Rect::new(0, 1, 3, 3), 2, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["aef", "~~~", "~~~", "dhi"]));
+        buffer.scroll_up(buffer.area, 10, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["~~~"; 4]));
+    }
+
+    #[test]
+    fn scroll_horizontally() {
+        let fill = Cell::default().set_symbol("~").clone();
+        let mut buffer = Buffer::with_lines(vec!["abcd", "efgh"]);
+        buffer.scroll_left(buffer.area, 1, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["bcd~", "fgh~"]));
+        buffer.scroll_right(Rect::new(0, 1, 4, 1), 2, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["bcd~", "~~fg"]));
+    }
+
+    #[test]
+    fn scroll_wide_symbols() {
+        let fill = Cell::default();
+        // 称 is split by the left edge of the region, 号 by its right edge
+        let mut buffer = Buffer::with_lines(vec!["称ab号"]);
+        buffer.scroll_left(Rect::new(1, 0, 4, 1), 1, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec![" ab   "]));
+
+        let mut buffer = Buffer::with_lines(vec!["a称b"]);
+        buffer.scroll_right(buffer.area, 1, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec![" a称"]));
+        buffer.scroll_right(buffer.area, 1, &fill);
+        assert_eq!(buffer, Buffer::with_lines(vec!["  a "]));
+    }
+}
@@ -0,0 +1,446 @@
+//! A compact, human readable text format for [`Buffer`] snapshots.
+
+use std::{error::Error, fmt, fs, path::Path, str::FromStr};
+
+use crate::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+    text::DisplayWidth,
+};
+
+/// Name of the environment variable enabling the update mode of [`assert_buffer_snapshot!`]: when
+/// it is set (to any value but `0`), snapshot files are overwritten instead of compared.
+///
+/// [`assert_buffer_snapshot!`]: crate::assert_buffer_snapshot
+pub const UPDATE_SNAPSHOTS_ENV: &str = "RATATUI_UPDATE_SNAPSHOTS";
+
+/// Key of the cells using the default style
+const DEFAULT_STYLE_KEY: char = '.';
+
+/// Error type indicating a failure to parse a buffer snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSnapshotError {
+    /// Line of the snapshot (starting at 1) where the error occurred
+    pub line: usize,
+    /// Description of the error
+    pub reason: String,
+}
+
+impl ParseSnapshotError {
+    fn new(line: usize, reason: impl Into<String>) -> ParseSnapshotError {
+        ParseSnapshotError {
+            line,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseSnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to parse snapshot at line {}: {}",
+            self.line, self.reason
+        )
+    }
+}
+
+impl Error for ParseSnapshotError {}
+
+/// The style properties of a cell
+type CellStyle = (Color, Color, Modifier);
+
+/// Returns the key used for the n-th style of a snapshot.
+fn style_key(n: usize) -> char {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .chain('0'..='9')
+        .nth(n)
+        // past the 62 alphanumeric keys, use the (single width) latin extended letters
+        .or_else(|| char::from_u32(0x100 + n as u32 - 62))
+        .expect("too many styles in buffer")
+}
+
+fn color_to_string(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Indexed(i) => i.to_string(),
+        color => format!("{color:?}"),
+    }
+}
+
+fn style_to_string((fg, bg, modifier): CellStyle) -> String {
+    let mut properties = vec![];
+    if fg != Color::Reset {
+        properties.push(format!("fg: {}", color_to_string(fg)));
+    }
+    if bg != Color::Reset {
+        properties.push(format!("bg: {}", color_to_string(bg)));
+    }
+    if !modifier.is_empty() {
+        properties.push(format!("modifier: {modifier:?}"));
+    }
+    properties.join(", ")
+}
+
+fn parse_style(line: usize, s: &str) -> Result<CellStyle, ParseSnapshotError> {
+    let mut style = (Color::Reset, Color::Reset, Modifier::empty());
+    for property in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, value) = property
+            .split_once(':')
+            .ok_or_else(|| ParseSnapshotError::new(line, format!("invalid property {property}")))?;
+        let value = value.trim();
+        let invalid = |_| ParseSnapshotError::new(line, format!("invalid {name} {value}"));
+        match name.trim() {
+            "fg" => style.0 = Color::from_str(value).map_err(invalid)?,
+            "bg" => style.1 = Color::from_str(value).map_err(invalid)?,
+            "modifier" => {
+                style.2 = bitflags::parser::from_str(value).map_err(|_| {
+                    ParseSnapshotError::new(line, format!("invalid modifier {value}"))
+                })?
+            }
+            name => {
+                return Err(ParseSnapshotError::new(
+                    line,
+                    format!("unknown property {name}"),
+                ))
+            }
+        }
+    }
+    Ok(style)
+}
+
+/// Returns the content of a quoted line.
+fn unquote(line: usize, s: &str) -> Result<&str, ParseSnapshotError> {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| ParseSnapshotError::new(line, "expected a quoted line"))
+}
+
+impl Buffer {
+    /// Returns a textual snapshot of the buffer with its styles.
+    ///
+    /// A snapshot starts with the content of the buffer, one quoted line per row. When some cells
+    /// are styled, it is followed by a `styles:` section, a second grid with one key per cell (`.`
+    /// for the default style), and a `legend:` section giving the style of each key:
+    ///
+    /// ```text
+    /// "Hello World!"
+    /// "G'day World!"
+    /// styles:
+    /// "aaaaa......."
+    /// "bbbbbbbbbbbb"
+    /// legend:
+    /// a: fg: Red, modifier: BOLD
+    /// b: fg: Green, bg: Yellow, modifier: BOLD | ITALIC
+    /// ```
+    ///
+    /// The style grid has exactly one key per cell, including the cells hidden by multi-width
+    /// symbols, so that it stays aligned with the content as displayed on screen. The symbols of
+    /// hidden cells are not part of the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::style::{Color, Style};
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+    /// buffer.set_string(0, 0, "Hi", Style::default().fg(Color::Red));
+    /// assert_eq!(
+    ///     buffer.to_snapshot(),
+    ///     "\"Hi   \"\nstyles:\n\"aa...\"\nlegend:\na: fg: Red\n"
+    /// );
+    /// ```
+    pub fn to_snapshot(&self) -> String {
+        let width = self.area.width as usize;
+        let mut snapshot = String::new();
+        for row in self.content.chunks(width.max(1)) {
+            let mut skip: usize = 0;
+            snapshot.push('"');
+            for cell in row {
+                if skip == 0 {
+                    snapshot.push_str(&cell.symbol);
+                }
+                skip = std::cmp::max(skip, cell.symbol.width()).saturating_sub(1);
+            }
+            snapshot.push_str("\"\n");
+        }
+
+        let default_style = (Color::Reset, Color::Reset, Modifier::empty());
+        let mut styles: Vec<CellStyle> = vec![];
+        let mut grid = String::new();
+        for row in self.content.chunks(width.max(1)) {
+            grid.push('"');
+            for cell in row {
+                let style = (cell.fg, cell.bg, cell.modifier);
+                if style == default_style {
+                    grid.push(DEFAULT_STYLE_KEY);
+                    continue;
+                }
+                let index = styles.iter().position(|s| *s == style).unwrap_or_else(|| {
+                    styles.push(style);
+                    styles.len() - 1
+                });
+                grid.push(style_key(index));
+            }
+            grid.push_str("\"\n");
+        }
+        if styles.is_empty() {
+            return snapshot;
+        }
+
+        snapshot.push_str("styles:\n");
+        snapshot.push_str(&grid);
+        snapshot.push_str("legend:\n");
+        for (i, style) in styles.into_iter().enumerate() {
+            snapshot.push_str(&format!("{}: {}\n", style_key(i), style_to_string(style)));
+        }
+        snapshot
+    }
+
+    /// Builds a buffer from a textual snapshot as produced by [`Buffer::to_snapshot`].
+    ///
+    /// Leading and trailing whitespace on each line is ignored, so snapshots can be indented in
+    /// source files. The area of the returned buffer starts at (0, 0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::style::{Color, Modifier};
+    /// let buffer = Buffer::from_snapshot(
+    ///     r#"
+    ///     "Hello"
+    ///     styles:
+    ///     "aa..b"
+    ///     legend:
+    ///     a: fg: Red
+    ///     b: bg: #1e1e2e, modifier: BOLD | ITALIC
+    ///     "#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(buffer.get(0, 0).fg, Color::Red);
+    /// assert_eq!(buffer.get(4, 0).modifier, Modifier::BOLD | Modifier::ITALIC);
+    /// ```
+    pub fn from_snapshot(snapshot: &str) -> Result<Buffer, ParseSnapshotError> {
+        let mut lines = snapshot
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .peekable();
+
+        let mut content = vec![];
+        while let Some((i, line)) = lines.next_if(|(_, line)| *line != "styles:") {
+            content.push(unquote(i, line)?);
+        }
+        let mut buffer = Buffer::with_lines(content);
+        if lines.next().is_none() {
+            return Ok(buffer);
+        }
+
+        let mut grid = vec![];
+        while let Some((i, line)) = lines.next_if(|(_, line)| *line != "legend:") {
+            let keys: Vec<char> = unquote(i, line)?.chars().collect();
+            if keys.len() != buffer.area.width as usize {
+                return Err(ParseSnapshotError::new(
+                    i,
+                    format!("expected {} style keys", buffer.area.width),
+                ));
+            }
+            grid.push((i, keys));
+        }
+        if grid.len() != buffer.area.height as usize {
+            let line = grid.last().map_or(0, |(i, _)| *i);
+            return Err(ParseSnapshotError::new(
+                line,
+                format!("expected {} lines of style keys", buffer.area.height),
+            ));
+        }
+        if lines.next().is_none() {
+            return Err(ParseSnapshotError::new(0, "missing legend"));
+        }
+
+        let mut legend = vec![];
+        for (i, line) in lines {
+            let (key, style) = line
+                .split_once(':')
+                .ok_or_else(|| ParseSnapshotError::new(i, "expected `key: style`"))?;
+            let mut key_chars = key.trim().chars();
+            let key = match (key_chars.next(), key_chars.next()) {
+                (Some(key), None) => key,
+                _ => {
+                    return Err(ParseSnapshotError::new(
+                        i,
+                        "keys must be a single character",
+                    ))
+                }
+            };
+            legend.push((key, parse_style(i, style)?));
+        }
+
+        for ((i, keys), y) in grid.into_iter().zip(0..) {
+            for (key, x) in keys.into_iter().zip(0..) {
+                if key == DEFAULT_STYLE_KEY {
+                    continue;
+                }
+                let (fg, bg, modifier) = legend
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, style)| *style)
+                    .ok_or_else(|| ParseSnapshotError::new(i, format!("unknown key {key}")))?;
+                let cell = buffer.get_mut(x, y);
+                cell.fg = fg;
+                cell.bg = bg;
+                cell.modifier = modifier;
+            }
+        }
+        Ok(buffer)
+    }
+}
+
+/// Compares the snapshot of a buffer with the content of a snapshot file.
+///
+/// When the [`UPDATE_SNAPSHOTS_ENV`] environment variable is set, the file (and its parent
+/// directories) are written instead. See [`assert_buffer_snapshot!`] for a more convenient way to
+/// call this function from tests.
+///
+/// # Panics
+///
+/// Panics if the snapshots differ or if the file cannot be read or written.
+///
+/// [`assert_buffer_snapshot!`]: crate::assert_buffer_snapshot
+pub fn assert_snapshot_file<P: AsRef<Path>>(buffer: &Buffer, path: P) {
+    let path = path.as_ref();
+    let actual = buffer.to_snapshot();
+    if matches!(std::env::var(UPDATE_SNAPSHOTS_ENV).as_deref(), Ok(value) if value != "0") {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("Failed to create {}: {err}", parent.display()));
+        }
+        fs::write(path, &actual)
+            .unwrap_or_else(|err| panic!("Failed to write {}: {err}", path.display()));
+        return;
+    }
+    let expected = fs::read_to_string(path).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read snapshot {}: {err}\nRun with {UPDATE_SNAPSHOTS_ENV}=1 to create it.\nGot:\n{actual}",
+            path.display()
+        )
+    });
+    let expected = expected.replace("\r\n", "\n");
+    assert!(
+        expected.trim_end() == actual.trim_end(),
+        "Snapshot {} does not match\nRun with {UPDATE_SNAPSHOTS_ENV}=1 to update it.\nExpected:\n{expected}\nGot:\n{actual}",
+        path.display()
+    );
+}
+
+/// Assert that a buffer matches a snapshot file.
+///
+/// The snapshot is stored in `tests/snapshots/<name>.snap` relative to the manifest directory of
+/// the crate being tested. Set the `RATATUI_UPDATE_SNAPSHOTS` environment variable to create or
+/// update the snapshot files:
+///
+/// ```sh
+/// RATATUI_UPDATE_SNAPSHOTS=1 cargo test
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui::assert_buffer_snapshot;
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::layout::Rect;
+/// let buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+/// assert_buffer_snapshot!(buffer, "empty_buffer");
+/// ```
+#[macro_export]
+macro_rules! assert_buffer_snapshot {
+    ($buffer:expr, $name:expr) => {
+        $crate::buffer::assert_snapshot_file(
+            &$buffer,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots")
+                .join(format!("{}.snap", $name)),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Rect, style::Style};
+
+    #[test]
+    fn snapshot_without_styles() {
+        let buffer = Buffer::with_lines(vec!["┌称号┐", "└───┘ "]);
+        assert_eq!(buffer.to_snapshot(), "\"┌称号┐\"\n\"└───┘ \"\n");
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "称号", Style::default().fg(Color::Rgb(30, 30, 46)));
+        buffer.set_string(
+            0,
+            1,
+            "ab",
+            Style::default()
+                .bg(Color::Indexed(42))
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        );
+        let snapshot = buffer.to_snapshot();
+        assert_eq!(
+            snapshot,
+            indoc::indoc! {r#"
+                "称号  "
+                "ab    "
+                styles:
+                "a.a..."
+                "bb...."
+                legend:
+                a: fg: #1e1e2e
+                b: bg: 42, modifier: BOLD | UNDERLINED
+            "#}
+        );
+        assert_eq!(Buffer::from_snapshot(&snapshot), Ok(buffer));
+    }
+
+    #[test]
+    fn parse_indented_snapshot() {
+        let buffer = Buffer::from_snapshot(
+            r#"
+            "ab"
+            styles:
+            ".a"
+            legend:
+            a: fg: LightRed
+            "#,
+        )
+        .unwrap();
+        let mut expected = Buffer::with_lines(vec!["ab"]);
+        expected.get_mut(1, 0).set_fg(Color::LightRed);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Buffer::from_snapshot("ab").unwrap_err();
+        assert_eq!(error, ParseSnapshotError::new(1, "expected a quoted line"));
+
+        let error =
+            Buffer::from_snapshot("\"ab\"\nstyles:\n\"a\"\nlegend:\na: fg: Red").unwrap_err();
+        assert_eq!(error, ParseSnapshotError::new(3, "expected 2 style keys"));
+
+        let error =
+            Buffer::from_snapshot("\"ab\"\nstyles:\n\"ab\"\nlegend:\na: fg: Red").unwrap_err();
+        assert_eq!(error, ParseSnapshotError::new(3, "unknown key b"));
+
+        let error =
+            Buffer::from_snapshot("\"a\"\nstyles:\n\"a\"\nlegend:\na: fg: Nope").unwrap_err();
+        assert_eq!(error, ParseSnapshotError::new(5, "invalid fg Nope"));
+    }
+}
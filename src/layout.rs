@@ -67,6 +67,14 @@ pub enum Alignment {
     Left,
     Center,
     Right,
+    /// Stretches the wrapped lines of a [`Paragraph`] to the full width by distributing the extra
+    /// space across the gaps between words. The last line of each paragraph, as well as text that
+    /// is not wrapped, is left aligned.
+    ///
+    /// Other widgets treat it as [`Alignment::Left`].
+    ///
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    Justify,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -7,6 +7,17 @@ use std::{
 
 use bitflags::bitflags;
 
+mod color;
+mod stylize;
+mod syntax;
+mod theme;
+pub(crate) use stylize::impl_styled;
+pub use stylize::{Styled, Stylize};
+#[cfg(feature = "serde")]
+pub use syntax::as_string;
+pub use syntax::ParseStyleError;
+pub use theme::{set_theme, theme, Theme};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
@@ -135,8 +146,16 @@ impl fmt::Debug for Modifier {
 ///     buffer.get(0, 0).style(),
 /// );
 /// ```
+///
+/// Styles can also be written in a human readable syntax, e.g. `bold red on black`, see
+/// [`Style::from_str`]. With the `serde` feature, styles are serialized as a struct of their
+/// fields, and the `style::as_string` module serializes them in that syntax instead.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Style {
     pub fg: Option<Color>,
     pub bg: Option<Color>,
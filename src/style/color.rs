@@ -0,0 +1,376 @@
+//! Conversions between color spaces and color arithmetic.
+
+use crate::style::Color;
+
+/// RGB values of the 16 base colors of the xterm palette
+const BASE_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Levels of each channel in the 6x6x6 color cube of the xterm palette (indexes 16 to 231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Returns the RGB values of the color, or `None` for [`Color::Reset`].
+    ///
+    /// Named and indexed colors are converted using the default xterm palette. Terminals may be
+    /// configured with another palette, in which case the actual colors differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Red.to_rgb(), Some((128, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Indexed(244).to_rgb(), Some((128, 128, 128)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        if let Color::Rgb(r, g, b) = self {
+            return Some((r, g, b));
+        }
+        let index = self.palette_index()?;
+        Some(match index {
+            0..=15 => BASE_COLORS[index as usize],
+            16..=231 => {
+                let i = index - 16;
+                (
+                    CUBE_LEVELS[(i / 36) as usize],
+                    CUBE_LEVELS[(i / 6 % 6) as usize],
+                    CUBE_LEVELS[(i % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (index - 232);
+                (level, level, level)
+            }
+        })
+    }
+
+    /// Returns the index of the closest color of the xterm 256 color palette, or `None` for
+    /// [`Color::Reset`].
+    ///
+    /// Named colors and [`Color::Indexed`] keep their index. Other colors are matched against the
+    /// color cube and the grayscale ramp only, as the 16 base colors are often redefined by the
+    /// terminal themes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Rgb(250, 10, 5).to_indexed(), Some(196));
+    /// assert_eq!(Color::Rgb(100, 100, 100).to_indexed(), Some(241));
+    /// assert_eq!(Color::Blue.to_indexed(), Some(4));
+    /// ```
+    pub fn to_indexed(self) -> Option<u8> {
+        if let Some(index) = self.palette_index() {
+            return Some(index);
+        }
+        let (r, g, b) = self.to_rgb()?;
+        let level = |c: u8| {
+            CUBE_LEVELS
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &level)| level.abs_diff(c))
+                .map_or(0, |(i, _)| i as u8)
+        };
+        let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+        let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+        let gray = 232 + ((average.saturating_sub(3) / 10).min(23) as u8);
+        let distance = |index: u8| {
+            let (ir, ig, ib) = Color::Indexed(index).to_rgb().unwrap_or_default();
+            [(r, ir), (g, ig), (b, ib)]
+                .iter()
+                .map(|&(a, b)| u32::from(a.abs_diff(b)).pow(2))
+                .sum::<u32>()
+        };
+        Some(if distance(gray) < distance(cube) {
+            gray
+        } else {
+            cube
+        })
+    }
+
+    /// Returns the index of a named or indexed color in the xterm palette
+    fn palette_index(self) -> Option<u8> {
+        Some(match self {
+            Color::Reset | Color::Rgb(..) => return None,
+            Color::Indexed(i) => i,
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::Gray => 7,
+            Color::DarkGray => 8,
+            Color::LightRed => 9,
+            Color::LightGreen => 10,
+            Color::LightYellow => 11,
+            Color::LightBlue => 12,
+            Color::LightMagenta => 13,
+            Color::LightCyan => 14,
+            Color::White => 15,
+        })
+    }
+
+    /// Creates a color from its hue (in degrees), saturation and lightness (between 0 and 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+    /// assert_eq!(Color::from_hsl(240.0, 1.0, 0.25), Color::Rgb(0, 0, 128));
+    /// ```
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Color {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        from_hue_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Returns the hue (in degrees), saturation and lightness (between 0 and 1) of the color, or
+    /// `None` for [`Color::Reset`].
+    pub fn to_hsl(self) -> Option<(f64, f64, f64)> {
+        let (max, min, hue) = hue_components(self.to_rgb()?);
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Some((hue, saturation, lightness))
+    }
+
+    /// Creates a color from its hue (in degrees), saturation and value (between 0 and 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::Rgb(0, 255, 0));
+    /// ```
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Color {
+        let value = value.clamp(0.0, 1.0);
+        let chroma = value * saturation.clamp(0.0, 1.0);
+        from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Returns the hue (in degrees), saturation and value (between 0 and 1) of the color, or
+    /// `None` for [`Color::Reset`].
+    pub fn to_hsv(self) -> Option<(f64, f64, f64)> {
+        let (max, min, hue) = hue_components(self.to_rgb()?);
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Some((hue, saturation, max))
+    }
+
+    /// Mixes the color with `other`: `ratio` is the weight of `other`, from 0 (this color) to 1
+    /// (`other`).
+    ///
+    /// The colors are mixed in the RGB space. [`Color::Reset`] cannot be mixed, so the closest
+    /// color of the two is returned when one of them is reset.
+    ///
+    /// # Examples
+    ///
+    /// A gradient of 5 colors, e.g. for the bars of a chart:
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// let gradient: Vec<Color> = (0..5)
+    ///     .map(|i| Color::Rgb(0, 0, 0).blend(Color::Rgb(0, 200, 100), i as f64 / 4.0))
+    ///     .collect();
+    /// assert_eq!(gradient[2], Color::Rgb(0, 100, 50));
+    /// ```
+    pub fn blend(self, other: Color, ratio: f64) -> Color {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if ratio < 0.5 { self } else { other };
+        };
+        let mix =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * ratio).round() as u8;
+        Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Returns the color with its lightness increased by `amount` (between 0 and 1), e.g. to
+    /// show that an element is hovered. [`Color::Reset`] is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Rgb(0, 0, 128).lighten(0.25), Color::Rgb(0, 0, 255));
+    /// ```
+    pub fn lighten(self, amount: f64) -> Color {
+        match self.to_hsl() {
+            Some((h, s, l)) => Color::from_hsl(h, s, l + amount),
+            None => self,
+        }
+    }
+
+    /// Returns the color with its lightness decreased by `amount` (between 0 and 1).
+    /// [`Color::Reset`] is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Rgb(255, 0, 0).darken(0.25), Color::Rgb(128, 0, 0));
+    /// ```
+    pub fn darken(self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Returns the relative luminance of the color as defined by the WCAG, between 0 (black) and
+    /// 1 (white), or `None` for [`Color::Reset`].
+    pub fn luminance(self) -> Option<f64> {
+        let (r, g, b) = self.to_rgb()?;
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Returns the WCAG contrast ratio between the color and `other`, from 1 (no contrast) to 21
+    /// (black and white), or `None` if one of them is [`Color::Reset`].
+    pub fn contrast_ratio(self, other: Color) -> Option<f64> {
+        let (a, b) = (self.luminance()?, other.luminance()?);
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Returns black or white, whichever contrasts the most with the color, e.g. to pick the
+    /// color of a text on a generated background. [`Color::Reset`] gives [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Rgb(255, 255, 0).contrast(), Color::Black);
+    /// assert_eq!(Color::Blue.contrast(), Color::White);
+    /// ```
+    pub fn contrast(self) -> Color {
+        match self.luminance() {
+            // the luminance for which both contrast ratios are equal
+            Some(luminance) if luminance > 0.179 => Color::Black,
+            Some(_) => Color::White,
+            None => Color::Reset,
+        }
+    }
+}
+
+/// Returns the largest and smallest channels (between 0 and 1) and the hue of a color
+fn hue_components((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (max, min, hue)
+}
+
+/// Creates a color from its hue, chroma and the value to add to each channel
+fn from_hue_chroma(hue: f64, chroma: f64, offset: f64) -> Color {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |c: f64| ((c + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexed_round_trip() {
+        for i in 16..=255 {
+            let (r, g, b) = Color::Indexed(i).to_rgb().unwrap();
+            assert_eq!(Color::Rgb(r, g, b).to_indexed(), Some(i), "{i}");
+        }
+    }
+
+    #[test]
+    fn named_colors_to_indexed() {
+        assert_eq!(Color::Black.to_indexed(), Some(0));
+        assert_eq!(Color::White.to_indexed(), Some(15));
+        assert_eq!(Color::Reset.to_indexed(), None);
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        for color in [
+            Color::Rgb(255, 0, 0),
+            Color::Rgb(12, 200, 97),
+            Color::Rgb(30, 30, 30),
+            Color::Rgb(250, 240, 230),
+        ] {
+            let (h, s, l) = color.to_hsl().unwrap();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+            let (h, s, v) = color.to_hsv().unwrap();
+            assert_eq!(Color::from_hsv(h, s, v), color);
+        }
+        assert_eq!(Color::Reset.to_hsl(), None);
+    }
+
+    #[test]
+    fn blend_reset() {
+        assert_eq!(Color::Reset.blend(Color::Red, 0.2), Color::Reset);
+        assert_eq!(Color::Reset.blend(Color::Red, 0.8), Color::Red);
+        assert_eq!(Color::Red.blend(Color::Blue, 0.0), Color::Rgb(128, 0, 0));
+    }
+
+    #[test]
+    fn lighten_and_darken_clamp() {
+        assert_eq!(Color::Gray.lighten(2.0), Color::Rgb(255, 255, 255));
+        assert_eq!(Color::Gray.darken(2.0), Color::Rgb(0, 0, 0));
+        assert_eq!(Color::Reset.lighten(0.5), Color::Reset);
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let ratio = Color::Black.contrast_ratio(Color::White).unwrap();
+        assert!((ratio - 21.0).abs() < 1e-9);
+        assert_eq!(Color::Red.contrast_ratio(Color::Red), Some(1.0));
+        assert_eq!(Color::Reset.contrast(), Color::Reset);
+    }
+}
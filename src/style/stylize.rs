@@ -0,0 +1,292 @@
+//! Shorthands to style text and widgets fluently, e.g. `"Hello".red().bold()`.
+
+use crate::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// A type that has a [`Style`] which can be changed, e.g. a text or a widget.
+///
+/// Implementing this trait gives access to the shorthands of [`Stylize`].
+pub trait Styled {
+    /// The type returned when the style is changed
+    type Item;
+
+    /// Returns the style of the object.
+    fn style(&self) -> Style;
+
+    /// Returns the object with the given style.
+    fn set_style(self, style: Style) -> Self::Item;
+}
+
+/// Generates the shorthands setting a foreground and a background color
+macro_rules! color_methods {
+    ($($color:ident: $fg:ident, $bg:ident;)+) => {
+        $(
+            #[doc = concat!("Sets the foreground color to [`Color::", stringify!($color), "`].")]
+            fn $fg(self) -> Self::Item {
+                self.fg(Color::$color)
+            }
+
+            #[doc = concat!("Sets the background color to [`Color::", stringify!($color), "`].")]
+            fn $bg(self) -> Self::Item {
+                self.bg(Color::$color)
+            }
+        )+
+    };
+}
+
+/// Generates the shorthands adding and removing a modifier
+macro_rules! modifier_methods {
+    ($($modifier:ident: $add:ident, $remove:ident;)+) => {
+        $(
+            #[doc = concat!("Adds the [`Modifier::", stringify!($modifier), "`] modifier.")]
+            fn $add(self) -> Self::Item {
+                self.add_modifier(Modifier::$modifier)
+            }
+
+            #[doc = concat!("Removes the [`Modifier::", stringify!($modifier), "`] modifier.")]
+            fn $remove(self) -> Self::Item {
+                self.remove_modifier(Modifier::$modifier)
+            }
+        )+
+    };
+}
+
+/// Shorthands to change the style of texts and widgets.
+///
+/// This trait is implemented for all the [`Styled`] types: strings (which give a [`Span`]),
+/// [`Span`], [`Line`], [`Text`], [`Style`] and the widgets with a `style` method. On texts made of
+/// several spans, the changes are patched on top of the style of each span.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::{Color, Modifier, Style, Stylize};
+/// # use ratatui::text::{Line, Span};
+/// # use ratatui::widgets::{Block, Borders};
+/// assert_eq!(
+///     "Hello".red().on_blue().bold(),
+///     Span::styled(
+///         "Hello",
+///         Style::default()
+///             .fg(Color::Red)
+///             .bg(Color::Blue)
+///             .add_modifier(Modifier::BOLD)
+///     )
+/// );
+///
+/// let line = Line::from(vec!["Hello".bold(), " world".into()]).italic();
+/// assert_eq!(line.spans[0].style, Style::default().add_modifier(Modifier::BOLD | Modifier::ITALIC));
+///
+/// let block = Block::default().borders(Borders::ALL).on_black().not_underlined();
+/// ```
+pub trait Stylize: Styled + Sized {
+    /// Sets the foreground color.
+    fn fg(self, color: Color) -> Self::Item {
+        let style = self.style().fg(color);
+        self.set_style(style)
+    }
+
+    /// Sets the background color.
+    fn bg(self, color: Color) -> Self::Item {
+        let style = self.style().bg(color);
+        self.set_style(style)
+    }
+
+    /// Adds the given modifiers.
+    fn add_modifier(self, modifier: Modifier) -> Self::Item {
+        let style = self.style().add_modifier(modifier);
+        self.set_style(style)
+    }
+
+    /// Removes the given modifiers.
+    fn remove_modifier(self, modifier: Modifier) -> Self::Item {
+        let style = self.style().remove_modifier(modifier);
+        self.set_style(style)
+    }
+
+    /// Resets all the properties of the style, see [`Style::reset`].
+    fn reset(self) -> Self::Item {
+        self.set_style(Style::reset())
+    }
+
+    color_methods! {
+        Black: black, on_black;
+        Red: red, on_red;
+        Green: green, on_green;
+        Yellow: yellow, on_yellow;
+        Blue: blue, on_blue;
+        Magenta: magenta, on_magenta;
+        Cyan: cyan, on_cyan;
+        Gray: gray, on_gray;
+        DarkGray: dark_gray, on_dark_gray;
+        LightRed: light_red, on_light_red;
+        LightGreen: light_green, on_light_green;
+        LightYellow: light_yellow, on_light_yellow;
+        LightBlue: light_blue, on_light_blue;
+        LightMagenta: light_magenta, on_light_magenta;
+        LightCyan: light_cyan, on_light_cyan;
+        White: white, on_white;
+    }
+
+    modifier_methods! {
+        BOLD: bold, not_bold;
+        DIM: dim, not_dim;
+        ITALIC: italic, not_italic;
+        UNDERLINED: underlined, not_underlined;
+        SLOW_BLINK: slow_blink, not_slow_blink;
+        RAPID_BLINK: rapid_blink, not_rapid_blink;
+        REVERSED: reversed, not_reversed;
+        HIDDEN: hidden, not_hidden;
+        CROSSED_OUT: crossed_out, not_crossed_out;
+    }
+}
+
+impl<T: Styled> Stylize for T {}
+
+/// Implements [`Styled`] for widgets having a `style` field and a `style` builder method.
+macro_rules! impl_styled {
+    ($($widget:ident),+) => {
+        $(
+            impl<'a> $crate::style::Styled for $widget<'a> {
+                type Item = $widget<'a>;
+
+                fn style(&self) -> $crate::style::Style {
+                    self.style
+                }
+
+                fn set_style(self, style: $crate::style::Style) -> Self::Item {
+                    self.style(style)
+                }
+            }
+        )+
+    };
+}
+pub(crate) use impl_styled;
+
+impl Styled for Style {
+    type Item = Style;
+
+    fn style(&self) -> Style {
+        *self
+    }
+
+    fn set_style(self, style: Style) -> Style {
+        style
+    }
+}
+
+impl<'a> Styled for &'a str {
+    type Item = Span<'a>;
+
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn set_style(self, style: Style) -> Span<'a> {
+        Span::styled(self, style)
+    }
+}
+
+impl Styled for String {
+    type Item = Span<'static>;
+
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn set_style(self, style: Style) -> Span<'static> {
+        Span::styled(self, style)
+    }
+}
+
+impl<'a> Styled for Span<'a> {
+    type Item = Span<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(mut self, style: Style) -> Span<'a> {
+        self.style = style;
+        self
+    }
+}
+
+/// The style of a line is the one patched on top of the style of its spans.
+impl<'a> Styled for Line<'a> {
+    type Item = Line<'a>;
+
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn set_style(mut self, style: Style) -> Line<'a> {
+        self.patch_style(style);
+        self
+    }
+}
+
+/// The style of a text is the one patched on top of the style of its spans.
+impl<'a> Styled for Text<'a> {
+    type Item = Text<'a>;
+
+    fn style(&self) -> Style {
+        Style::default()
+    }
+
+    fn set_style(mut self, style: Style) -> Text<'a> {
+        self.patch_style(style);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stylize_strings() {
+        let style = Style::default().fg(Color::Green).bg(Color::Black);
+        assert_eq!("a".green().on_black(), Span::styled("a", style));
+        assert_eq!(
+            String::from("a").green().on_black(),
+            Span::styled("a", style)
+        );
+    }
+
+    #[test]
+    fn stylize_style() {
+        assert_eq!(
+            Style::default().bold().not_italic().light_cyan(),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD)
+                .remove_modifier(Modifier::ITALIC)
+        );
+        assert_eq!(Style::default().red().reset(), Style::reset());
+    }
+
+    #[test]
+    fn stylize_text_patches_spans() {
+        let text = Text::from(vec![
+            Line::from(vec!["a".red(), "b".into()]),
+            Line::from("c".bold()),
+        ])
+        .on_blue()
+        .underlined();
+        let patch = Style::default()
+            .bg(Color::Blue)
+            .add_modifier(Modifier::UNDERLINED);
+        assert_eq!(
+            text.lines[0].spans[0].style,
+            Style::default().fg(Color::Red).patch(patch)
+        );
+        assert_eq!(text.lines[0].spans[1].style, patch);
+        assert_eq!(
+            text.lines[1].spans[0].style,
+            Style::default().add_modifier(Modifier::BOLD).patch(patch)
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! A human readable text syntax for styles, e.g. `bold italic red on #1e1e2e`.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::style::{Color, Modifier, Style};
+
+/// Names of the modifiers in the style syntax
+const MODIFIER_NAMES: [(Modifier, &str); 9] = [
+    (Modifier::BOLD, "bold"),
+    (Modifier::DIM, "dim"),
+    (Modifier::ITALIC, "italic"),
+    (Modifier::UNDERLINED, "underlined"),
+    (Modifier::SLOW_BLINK, "slow_blink"),
+    (Modifier::RAPID_BLINK, "rapid_blink"),
+    (Modifier::REVERSED, "reversed"),
+    (Modifier::HIDDEN, "hidden"),
+    (Modifier::CROSSED_OUT, "crossed_out"),
+];
+
+/// Error type indicating a failure to parse a style or a modifier string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    reason: String,
+}
+
+impl ParseStyleError {
+    fn new(reason: impl Into<String>) -> ParseStyleError {
+        ParseStyleError {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse style: {}", self.reason)
+    }
+}
+
+impl Error for ParseStyleError {}
+
+fn modifier_from_name(name: &str) -> Option<Modifier> {
+    MODIFIER_NAMES
+        .iter()
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+        .map(|&(modifier, _)| modifier)
+}
+
+fn parse_color(s: &str) -> Result<Color, ParseStyleError> {
+    Color::from_str(s).map_err(|_| ParseStyleError::new(format!("invalid color {s}")))
+}
+
+/// Formats the color with the syntax accepted by [`Color::from_str`]: a lowercase name, a
+/// `#rrggbb` hexadecimal value or a palette index.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::Color;
+/// assert_eq!(Color::LightBlue.to_string(), "lightblue");
+/// assert_eq!(Color::Rgb(30, 30, 46).to_string(), "#1e1e2e");
+/// assert_eq!(Color::Indexed(42).to_string(), "42");
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Color::Reset => "reset",
+            Color::Black => "black",
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+            Color::Gray => "gray",
+            Color::DarkGray => "darkgray",
+            Color::LightRed => "lightred",
+            Color::LightGreen => "lightgreen",
+            Color::LightYellow => "lightyellow",
+            Color::LightBlue => "lightblue",
+            Color::LightMagenta => "lightmagenta",
+            Color::LightCyan => "lightcyan",
+            Color::White => "white",
+            Color::Rgb(r, g, b) => return write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            Color::Indexed(i) => return write!(f, "{i}"),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Formats the modifier as the names of its flags separated by spaces, e.g. `bold italic`. The
+/// empty modifier gives an empty string.
+impl fmt::Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (modifier, name) in MODIFIER_NAMES {
+            if self.contains(modifier) {
+                if !first {
+                    f.write_str(" ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the names of modifiers separated by whitespace, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::Modifier;
+/// let modifier: Modifier = "bold crossed_out".parse().unwrap();
+/// assert_eq!(modifier, Modifier::BOLD | Modifier::CROSSED_OUT);
+/// assert_eq!(modifier.to_string(), "bold crossed_out");
+/// ```
+impl FromStr for Modifier {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace()
+            .try_fold(Modifier::empty(), |acc, name| {
+                modifier_from_name(name)
+                    .map(|modifier| acc | modifier)
+                    .ok_or_else(|| ParseStyleError::new(format!("unknown modifier {name}")))
+            })
+    }
+}
+
+/// Formats the style with the syntax accepted by [`Style::from_str`].
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut words = vec![];
+        if !self.add_modifier.is_empty() {
+            words.push(self.add_modifier.to_string());
+        }
+        for (modifier, name) in MODIFIER_NAMES {
+            if self.sub_modifier.contains(modifier) {
+                words.push(format!("not {name}"));
+            }
+        }
+        if let Some(fg) = self.fg {
+            words.push(fg.to_string());
+        }
+        if let Some(bg) = self.bg {
+            words.push(format!("on {bg}"));
+        }
+        f.write_str(&words.join(" "))
+    }
+}
+
+/// Parses a style from a list of words separated by whitespace:
+///
+/// * a modifier name (`bold`, `dim`, `italic`, `underlined`, `slow_blink`, `rapid_blink`,
+///   `reversed`, `hidden` or `crossed_out`) adds the modifier,
+/// * `not` followed by a modifier name removes the modifier,
+/// * a color (see [`Color::from_str`]) sets the foreground color,
+/// * `on` followed by a color sets the background color.
+///
+/// The words are case insensitive and an empty string gives the default style.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::{Color, Modifier, Style};
+/// let style: Style = "bold italic red on #1e1e2e".parse().unwrap();
+/// assert_eq!(
+///     style,
+///     Style::default()
+///         .fg(Color::Red)
+///         .bg(Color::Rgb(30, 30, 46))
+///         .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+/// );
+/// assert_eq!(style.to_string(), "bold italic red on #1e1e2e");
+///
+/// let style: Style = "not underlined on blue".parse().unwrap();
+/// assert_eq!(
+///     style,
+///     Style::default()
+///         .bg(Color::Blue)
+///         .remove_modifier(Modifier::UNDERLINED)
+/// );
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::default();
+        let mut words = s.split_whitespace();
+        while let Some(word) = words.next() {
+            if word.eq_ignore_ascii_case("on") {
+                let color = words
+                    .next()
+                    .ok_or_else(|| ParseStyleError::new("expected a color after on"))?;
+                if style.bg.is_some() {
+                    return Err(ParseStyleError::new("several background colors"));
+                }
+                style.bg = Some(parse_color(color)?);
+            } else if word.eq_ignore_ascii_case("not") {
+                let name = words
+                    .next()
+                    .ok_or_else(|| ParseStyleError::new("expected a modifier after not"))?;
+                let modifier = modifier_from_name(name)
+                    .ok_or_else(|| ParseStyleError::new(format!("unknown modifier {name}")))?;
+                style = style.remove_modifier(modifier);
+            } else if let Some(modifier) = modifier_from_name(word) {
+                style = style.add_modifier(modifier);
+            } else {
+                let color = Color::from_str(word)
+                    .map_err(|_| ParseStyleError::new(format!("unknown word {word}")))?;
+                if style.fg.is_some() {
+                    return Err(ParseStyleError::new("several foreground colors"));
+                }
+                style.fg = Some(color);
+            }
+        }
+        Ok(style)
+    }
+}
+
+/// Serializes a [`Style`] as a string in the syntax of [`Style::from_str`], for use with
+/// `#[serde(with = "ratatui::style::as_string")]`.
+///
+/// A style is serialized as a struct of its fields by default: this module suits human written
+/// configuration files, where `"bold red on black"` is easier to read and write.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::{Color, Style};
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "ratatui::style::as_string")]
+///     warning: Style,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "warning": "bold yellow" }"#).unwrap();
+/// assert_eq!(config.warning.fg, Some(Color::Yellow));
+/// ```
+#[cfg(feature = "serde")]
+pub mod as_string {
+    use std::str::FromStr;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::style::Style;
+
+    /// Serializes the style as a string.
+    pub fn serialize<S: Serializer>(style: &Style, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(style)
+    }
+
+    /// Deserializes the style from a string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Style::from_str(&s).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_round_trip() {
+        for color in [
+            Color::Reset,
+            Color::DarkGray,
+            Color::LightMagenta,
+            Color::White,
+            Color::Rgb(0, 128, 255),
+            Color::Indexed(200),
+        ] {
+            assert_eq!(Color::from_str(&color.to_string()).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn style_round_trip() {
+        for style in [
+            Style::default(),
+            Style::reset(),
+            Style::default().fg(Color::Indexed(3)),
+            Style::default()
+                .bg(Color::Green)
+                .add_modifier(Modifier::all())
+                .remove_modifier(Modifier::DIM),
+        ] {
+            assert_eq!(Style::from_str(&style.to_string()).unwrap(), style);
+        }
+    }
+
+    #[test]
+    fn parse_style_is_case_insensitive() {
+        assert_eq!(
+            Style::from_str("  BOLD Not Italic LightRed ON Black ").unwrap(),
+            Style::default()
+                .fg(Color::LightRed)
+                .bg(Color::Black)
+                .add_modifier(Modifier::BOLD)
+                .remove_modifier(Modifier::ITALIC)
+        );
+    }
+
+    #[test]
+    fn parse_style_errors() {
+        let error = |s: &str| Style::from_str(s).unwrap_err().to_string();
+        assert_eq!(
+            error("bold on"),
+            "Failed to parse style: expected a color after on"
+        );
+        assert_eq!(
+            error("red blue"),
+            "Failed to parse style: several foreground colors"
+        );
+        assert_eq!(
+            error("on red on blue"),
+            "Failed to parse style: several background colors"
+        );
+        assert_eq!(
+            error("not red"),
+            "Failed to parse style: unknown modifier red"
+        );
+        assert_eq!(
+            error("bolder"),
+            "Failed to parse style: unknown word bolder"
+        );
+        assert_eq!(
+            error("on #12345"),
+            "Failed to parse style: invalid color #12345"
+        );
+        assert!(Modifier::from_str("bold red").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_style() {
+        let style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let json = serde_json::to_string(&style).unwrap();
+        assert!(json.starts_with('{'), "{json}");
+        assert_eq!(serde_json::from_str::<Style>(&json).unwrap(), style);
+        assert_eq!(
+            serde_json::from_str::<Style>(r#"{ "fg": "Red", "add_modifier": "BOLD" }"#).unwrap(),
+            style
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_style_as_string() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "as_string")]
+            style: Style,
+        }
+
+        let config = Config {
+            style: Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        };
+        let json = r#"{"style":"bold red"}"#;
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Config>(json).unwrap(), config);
+        assert!(serde_json::from_str::<Config>(r#"{"style":"bold on"}"#)
+            .unwrap_err()
+            .to_string()
+            .contains("expected a color after on"));
+    }
+}
@@ -0,0 +1,132 @@
+//! Semantic styles shared by the built-in widgets.
+
+use std::sync::{PoisonError, RwLock};
+
+use crate::style::Style;
+
+/// The theme used by the built-in widgets, set with [`set_theme`]
+static THEME: RwLock<Theme> = RwLock::new(Theme::new());
+
+/// A set of styles for the semantic roles of the elements of a user interface.
+///
+/// The built-in widgets consult the current theme, set with [`set_theme`], when rendering: the
+/// style given to a widget is patched on top of the style of the corresponding role, so the
+/// theme applies to whatever the widget does not set explicitly.
+///
+/// | Role        | Used by                                                           |
+/// |-------------|-------------------------------------------------------------------|
+/// | `base`      | the whole area of `Block`, `List`, `Table`, `Tabs` and gauges     |
+/// | `border`    | the borders of `Block`                                            |
+/// | `title`     | the titles of `Block`                                             |
+/// | `header`    | the header row of `Table`                                         |
+/// | `highlight` | the selected tab of `Tabs` and the filled part of gauges          |
+/// | `selection` | the selected item of `List` and the selected row of `Table`       |
+/// | `muted`     | not used by the built-in widgets, e.g. for hints and placeholders |
+/// | `error`     | not used by the built-in widgets, e.g. for error messages         |
+///
+/// The default theme leaves every style unset, so that widgets look as if there were no theme.
+///
+/// With the `serde` feature, a theme can be loaded from any format supported by serde, each role
+/// being written in the style syntax (e.g. `"bold red on black"`). The missing roles keep their
+/// default style.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::style::{set_theme, Color, Modifier, Style, Theme};
+/// set_theme(Theme {
+///     border: Style::default().fg(Color::DarkGray),
+///     title: Style::default().add_modifier(Modifier::BOLD),
+///     selection: Style::default().bg(Color::Blue),
+///     ..Theme::default()
+/// });
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Theme {
+    /// Style of the whole area of the widgets
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub base: Style,
+    /// Style of the borders
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub border: Style,
+    /// Style of the titles
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub title: Style,
+    /// Style of the headers
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub header: Style,
+    /// Style of the highlighted elements, e.g. the active tab
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub highlight: Style,
+    /// Style of the selected items
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub selection: Style,
+    /// Style of the secondary elements
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub muted: Style,
+    /// Style of the errors
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::as_string"))]
+    pub error: Style,
+}
+
+impl Theme {
+    /// Returns a theme leaving every style unset.
+    pub const fn new() -> Theme {
+        Theme {
+            base: Style::new(),
+            border: Style::new(),
+            title: Style::new(),
+            header: Style::new(),
+            highlight: Style::new(),
+            selection: Style::new(),
+            muted: Style::new(),
+            error: Style::new(),
+        }
+    }
+}
+
+/// Sets the [`Theme`] consulted by the built-in widgets.
+///
+/// The theme is global to the process and can be swapped at any time: the widgets rendered after
+/// the call use the new theme.
+pub fn set_theme(theme: Theme) {
+    *THEME.write().unwrap_or_else(PoisonError::into_inner) = theme;
+}
+
+/// Returns the [`Theme`] consulted by the built-in widgets.
+pub fn theme() -> Theme {
+    *THEME.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_theme_is_unset() {
+        assert_eq!(Theme::new(), Theme::default());
+        assert_eq!(Theme::new().border, Style::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_partial_theme() {
+        use crate::style::{Color, Modifier};
+
+        let theme: Theme =
+            serde_json::from_str(r#"{ "border": "darkgray", "error": "bold red" }"#).unwrap();
+        assert_eq!(
+            theme,
+            Theme {
+                border: Style::default().fg(Color::DarkGray),
+                error: Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ..Theme::default()
+            }
+        );
+    }
+}
@@ -2,9 +2,9 @@ use std::io;
 
 use crate::{
     backend::{Backend, ClearType},
-    buffer::Buffer,
+    buffer::{BlitOptions, Buffer, DiffSegment},
     layout::Rect,
-    widgets::{StatefulWidget, Widget},
+    widgets::{StatefulWidget, StatefulWidgetRef, Widget, WidgetRef},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -123,6 +123,90 @@ where
         widget.render(area, self.terminal.current_buffer_mut(), state);
     }
 
+    /// Render a [`WidgetRef`], e.g. a boxed trait object, to the current buffer using
+    /// [`WidgetRef::render_ref`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::Terminal;
+    /// # use ratatui::backend::TestBackend;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::{Block, WidgetRef};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// let block: Box<dyn WidgetRef> = Box::new(Block::default());
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut frame = terminal.get_frame();
+    /// frame.render_widget_ref(block.as_ref(), area);
+    /// ```
+    pub fn render_widget_ref<W>(&mut self, widget: &W, area: Rect)
+    where
+        W: WidgetRef + ?Sized,
+    {
+        widget.render_ref(area, self.terminal.current_buffer_mut());
+    }
+
+    /// Render a [`StatefulWidgetRef`], e.g. a boxed trait object, to the current buffer using
+    /// [`StatefulWidgetRef::render_ref`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::Terminal;
+    /// # use ratatui::backend::TestBackend;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::{List, ListItem, ListState, StatefulWidgetRef};
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// let mut state = ListState::default();
+    /// let list: Box<dyn StatefulWidgetRef<State = ListState>> =
+    ///     Box::new(List::new(vec![ListItem::new("Item 1")]));
+    /// let area = Rect::new(0, 0, 5, 5);
+    /// let mut frame = terminal.get_frame();
+    /// frame.render_stateful_widget_ref(list.as_ref(), area, &mut state);
+    /// ```
+    pub fn render_stateful_widget_ref<W>(&mut self, widget: &W, area: Rect, state: &mut W::State)
+    where
+        W: StatefulWidgetRef + ?Sized,
+    {
+        widget.render_ref(area, self.terminal.current_buffer_mut(), state);
+    }
+
+    /// Draw a [`Buffer`], e.g. one rendered offscreen with [`render_offscreen`], at the top left
+    /// corner of the given area.
+    ///
+    /// The cells of `buffer` are copied as is. Only the cells inside `area` are updated: the parts
+    /// of `buffer` that do not fit are clipped. Use [`Buffer::blit`] on
+    /// [`Terminal::current_buffer_mut`] for more control on how the cells are composited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::Terminal;
+    /// # use ratatui::backend::TestBackend;
+    /// # use ratatui::layout::Rect;
+    /// # use ratatui::widgets::{render_offscreen, Block, Borders};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// // Render once...
+    /// let cached = render_offscreen(Block::default().borders(Borders::ALL), 10, 10);
+    /// // ...and draw as many times as needed
+    /// let mut frame = terminal.get_frame();
+    /// frame.render_buffer(&cached, Rect::new(0, 0, 5, 5));
+    /// ```
+    ///
+    /// [`render_offscreen`]: crate::widgets::render_offscreen
+    pub fn render_buffer(&mut self, buffer: &Buffer, area: Rect) {
+        self.terminal.current_buffer_mut().blit_clipped(
+            buffer,
+            area.x,
+            area.y,
+            area,
+            BlitOptions::default(),
+        );
+    }
+
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
@@ -215,14 +299,18 @@ where
 
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
+    ///
+    /// The partially overwritten multi-width symbols of the current buffer are repaired first, see
+    /// [`Buffer::repair_wide_symbols`].
     pub fn flush(&mut self) -> io::Result<()> {
+        self.buffers[self.current].repair_wide_symbols();
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
-        let updates = previous_buffer.diff(current_buffer);
-        if let Some((col, row, _)) = updates.last() {
-            self.last_known_cursor_pos = (*col, *row);
+        let segments = previous_buffer.diff_segments(current_buffer);
+        if let Some((col, row, _)) = segments.last().and_then(DiffSegment::last_cell) {
+            self.last_known_cursor_pos = (col, row);
         }
-        self.backend.draw(updates.into_iter())
+        self.backend.draw_segments(segments.into_iter())
     }
 
     /// Updates the Terminal so that internal buffers match the requested size. Requested size will
@@ -347,6 +435,48 @@ where
         Ok(())
     }
 
+    /// Restores the terminal to its normal state, e.g. before spawning `$EDITOR` or stopping the
+    /// process on `SIGTSTP`.
+    ///
+    /// The backend leaves the alternate screen and raw mode and shows the cursor. Call
+    /// [`Terminal::resume`] to get back to the application. The termion backend does not handle
+    /// the raw mode, which belongs to its writer, see `TermionBackend::writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::process::Command;
+    /// # use ratatui::{backend::CrosstermBackend, Terminal};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let mut terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
+    /// terminal.suspend()?;
+    /// Command::new("vim").arg("notes.txt").status()?;
+    /// terminal.resume()?;
+    /// // the next call to `draw` repaints the whole screen
+    /// terminal.draw(|f| {})?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn suspend(&mut self) -> io::Result<()> {
+        self.backend.suspend()?;
+        self.hidden_cursor = false;
+        self.backend.flush()
+    }
+
+    /// Gets back to the state of the terminal before [`Terminal::suspend`].
+    ///
+    /// The backend enters the alternate screen and raw mode again. As the size of the terminal and
+    /// the content of the screen may have changed in the meantime, the size is queried again and
+    /// the internal buffers are reset so that the next [`Terminal::draw`] redraws everything.
+    pub fn resume(&mut self) -> io::Result<()> {
+        self.backend.resume()?;
+        let size = self.size()?;
+        self.resize(size)?;
+        self.buffers[0].reset();
+        self.buffers[1].reset();
+        self.backend.flush()
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
@@ -428,7 +558,7 @@ where
 
         draw_fn(&mut buffer);
 
-        let iter = buffer.content.iter().enumerate().map(|(i, c)| {
+        let iter = buffer.content().iter().enumerate().map(|(i, c)| {
             let (x, y) = buffer.pos_of(i);
             (x, y, c)
         });
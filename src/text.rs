@@ -49,13 +49,17 @@
 use std::{borrow::Cow, fmt::Debug};
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
-use crate::style::Style;
+use crate::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
 
+mod ellipsis;
 mod line;
 mod masked;
 mod spans;
+mod width;
+pub(crate) use ellipsis::kept_ranges;
+pub use ellipsis::{Ellipsis, EllipsisPosition};
+pub use width::{set_width_strategy, width_strategy, DisplayWidth, WidthStrategy};
 #[allow(deprecated)]
 pub use {line::Line, masked::Masked, spans::Spans};
 
@@ -119,6 +123,26 @@ impl<'a> Span<'a> {
         self.content.width()
     }
 
+    /// Returns the span truncated to `max_width` columns with the given [`Ellipsis`], or a copy of
+    /// the span if it fits.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use ratatui::text::{Ellipsis, Span};
+    /// let span = Span::raw("src/widgets/paragraph.rs");
+    /// assert_eq!(span.truncated(12, Ellipsis::middle()).content, "src/wi…ph.rs");
+    /// assert_eq!(span.truncated(30, Ellipsis::middle()), span);
+    /// ```
+    pub fn truncated(&self, max_width: usize, ellipsis: Ellipsis<'_>) -> Span<'a> {
+        let mut line = Line::from(self.clone()).truncated(max_width, ellipsis);
+        if line.spans.len() == 1 {
+            line.spans.remove(0)
+        } else {
+            Span::styled(String::from(line), self.style)
+        }
+    }
+
     /// Returns an iterator over the graphemes held by this span.
     ///
     /// `base_style` is the [`Style`] that will be patched with each grapheme [`Style`] to get
@@ -226,6 +250,22 @@ impl<'a> Span<'a> {
     }
 }
 
+/// Renders the span on the first row of the area, truncated at the width of the area.
+impl Widget for &Span<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_span(area.x, area.y, self, area.width);
+    }
+}
+
+impl Widget for Span<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
 impl<'a> From<String> for Span<'a> {
     fn from(s: String) -> Span<'a> {
         Span::raw(s)
@@ -383,6 +423,29 @@ impl<'a> Text<'a> {
     }
 }
 
+/// Renders each line of the text on a row of the area, see the [`Widget`] implementation of
+/// [`Line`]. The lines that do not fit in the area are not rendered.
+impl Widget for &Text<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for (y, line) in (area.top()..area.bottom()).zip(self.lines.iter()) {
+            line.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+}
+
+impl Widget for Text<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
 impl<'a> From<String> for Text<'a> {
     fn from(s: String) -> Text<'a> {
         Text::raw(s)
@@ -457,3 +520,31 @@ where
         self.lines.extend(lines);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Alignment, style::Color};
+
+    #[test]
+    fn render_span() {
+        let span = Span::styled("hello", Style::default().fg(Color::Red));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        span.render(Rect::new(1, 1, 3, 1), &mut buffer);
+        let mut expected = Buffer::with_lines(vec!["    ", " hel"]);
+        expected.set_style(Rect::new(1, 1, 3, 1), Style::default().fg(Color::Red));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_text() {
+        let text = Text::from(vec![
+            Line::from("a"),
+            Line::from("b").alignment(Alignment::Right),
+            Line::from("c"),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        (&text).render(Rect::new(0, 1, 3, 2), &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["   ", "a  ", "  b"]));
+    }
+}
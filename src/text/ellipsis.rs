@@ -0,0 +1,166 @@
+//! Truncation of overflowing text with a marker, e.g. `Hello w…`.
+
+use std::ops::Range;
+
+/// The part of a text replaced by the marker of an [`Ellipsis`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EllipsisPosition {
+    /// Keeps the start of the text, e.g. `Hello w…`
+    #[default]
+    End,
+    /// Keeps the end of the text, e.g. `…o world`
+    Start,
+    /// Keeps both ends of the text, e.g. `/home/…/file.rs`, which suits file paths and URLs
+    Middle,
+}
+
+/// How a text that does not fit in its area is truncated.
+///
+/// The overflowing graphemes are replaced by the `symbol` marker, which takes the style of the
+/// first grapheme it replaces. Wide graphemes are never split: a cell may stay empty next to the
+/// marker instead. When the marker itself does not fit, the text is truncated without it.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::text::{Ellipsis, EllipsisPosition, Line};
+/// let line = Line::from("/home/user/projects/ratatui/src/lib.rs");
+/// assert_eq!(String::from(line.truncated(10, Ellipsis::end())), "/home/use…");
+/// assert_eq!(String::from(line.truncated(10, Ellipsis::start())), "…rc/lib.rs");
+/// assert_eq!(String::from(line.truncated(10, Ellipsis::middle())), "/home…b.rs");
+///
+/// let ellipsis = Ellipsis {
+///     symbol: "...",
+///     ..Ellipsis::end()
+/// };
+/// assert_eq!(String::from(line.truncated(10, ellipsis)), "/home/u...");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ellipsis<'a> {
+    /// Marker replacing the truncated graphemes
+    pub symbol: &'a str,
+    /// Where the text is truncated
+    pub position: EllipsisPosition,
+}
+
+impl Ellipsis<'static> {
+    /// Truncates the end of the text with `…`.
+    pub const fn end() -> Ellipsis<'static> {
+        Ellipsis {
+            symbol: "…",
+            position: EllipsisPosition::End,
+        }
+    }
+
+    /// Truncates the start of the text with `…`.
+    pub const fn start() -> Ellipsis<'static> {
+        Ellipsis {
+            symbol: "…",
+            position: EllipsisPosition::Start,
+        }
+    }
+
+    /// Truncates the middle of the text with `…`.
+    pub const fn middle() -> Ellipsis<'static> {
+        Ellipsis {
+            symbol: "…",
+            position: EllipsisPosition::Middle,
+        }
+    }
+}
+
+impl Default for Ellipsis<'static> {
+    fn default() -> Self {
+        Ellipsis::end()
+    }
+}
+
+/// Returns the ranges of the graphemes kept before and after the marker when graphemes of the
+/// given widths are truncated to `max_width`, or `None` if they fit.
+///
+/// The marker width must be at most `max_width`.
+pub(crate) fn kept_ranges(
+    widths: &[usize],
+    max_width: usize,
+    marker_width: usize,
+    position: EllipsisPosition,
+) -> Option<(Range<usize>, Range<usize>)> {
+    if widths.iter().sum::<usize>() <= max_width {
+        return None;
+    }
+    let available_width = max_width - marker_width;
+    let head_max_width = match position {
+        EllipsisPosition::End => available_width,
+        EllipsisPosition::Start => 0,
+        EllipsisPosition::Middle => (available_width + 1) / 2,
+    };
+    let (mut head_end, mut head_width) = (0, 0);
+    for &width in widths {
+        if head_width + width > head_max_width {
+            break;
+        }
+        head_width += width;
+        head_end += 1;
+    }
+    // The tail takes the room left by the head, e.g. when a wide grapheme did not fit in it
+    let tail_max_width = match position {
+        EllipsisPosition::End => 0,
+        EllipsisPosition::Start | EllipsisPosition::Middle => available_width - head_width,
+    };
+    let (mut tail_start, mut tail_width) = (widths.len(), 0);
+    while tail_start > head_end && tail_width + widths[tail_start - 1] <= tail_max_width {
+        tail_width += widths[tail_start - 1];
+        tail_start -= 1;
+    }
+    Some((0..head_end, tail_start..widths.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kept_ranges_of_fitting_text() {
+        assert_eq!(kept_ranges(&[1, 1, 1], 3, 1, EllipsisPosition::End), None);
+        assert_eq!(kept_ranges(&[], 0, 0, EllipsisPosition::Middle), None);
+    }
+
+    #[test]
+    fn kept_ranges_by_position() {
+        let widths = [1; 10];
+        assert_eq!(
+            kept_ranges(&widths, 5, 1, EllipsisPosition::End),
+            Some((0..4, 10..10))
+        );
+        assert_eq!(
+            kept_ranges(&widths, 5, 1, EllipsisPosition::Start),
+            Some((0..0, 6..10))
+        );
+        assert_eq!(
+            kept_ranges(&widths, 6, 1, EllipsisPosition::Middle),
+            Some((0..3, 8..10))
+        );
+        assert_eq!(
+            kept_ranges(&widths, 3, 3, EllipsisPosition::Middle),
+            Some((0..0, 10..10))
+        );
+    }
+
+    #[test]
+    fn kept_ranges_do_not_split_wide_graphemes() {
+        let widths = [2, 2, 1, 1, 1, 1];
+        assert_eq!(
+            kept_ranges(&widths, 4, 1, EllipsisPosition::End),
+            Some((0..1, 6..6))
+        );
+        assert_eq!(
+            kept_ranges(&widths, 4, 1, EllipsisPosition::Start),
+            Some((0..0, 3..6))
+        );
+        // The tail gets the cell the head could not use
+        assert_eq!(
+            kept_ranges(&widths, 6, 1, EllipsisPosition::Middle),
+            Some((0..1, 3..6))
+        );
+    }
+}
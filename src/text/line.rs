@@ -1,6 +1,14 @@
 #![allow(deprecated)]
-use super::{Span, Spans, Style};
-use crate::layout::Alignment;
+use std::{borrow::Cow, ops::Range};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{kept_ranges, DisplayWidth, Ellipsis, Span, Spans, Style};
+use crate::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    widgets::Widget,
+};
 
 #[derive(Debug, Clone, PartialEq, Default, Eq)]
 pub struct Line<'a> {
@@ -26,6 +34,86 @@ impl<'a> Line<'a> {
         self.spans.iter().map(Span::width).sum()
     }
 
+    /// Returns the line truncated to `max_width` columns with the given [`Ellipsis`], or a copy of
+    /// the line if it fits.
+    ///
+    /// The truncation is grapheme and width aware, and the spans keep their style.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use ratatui::text::{Ellipsis, Span, Line};
+    /// # use ratatui::style::{Color, Style};
+    /// let line = Line::from(vec![
+    ///     Span::styled("My", Style::default().fg(Color::Yellow)),
+    ///     Span::raw(" long text"),
+    /// ]);
+    /// assert_eq!(
+    ///     line.truncated(6, Ellipsis::end()),
+    ///     Line::from(vec![
+    ///         Span::styled("My", Style::default().fg(Color::Yellow)),
+    ///         Span::raw(" lo"),
+    ///         Span::raw("…"),
+    ///     ])
+    /// );
+    /// ```
+    pub fn truncated(&self, max_width: usize, ellipsis: Ellipsis<'_>) -> Line<'a> {
+        // Span index, byte range and width of each grapheme
+        let graphemes: Vec<(usize, Range<usize>, usize)> = self
+            .spans
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| {
+                span.content
+                    .grapheme_indices(true)
+                    .map(move |(start, g)| (i, start..start + g.len(), g.width()))
+            })
+            .collect();
+        let widths: Vec<usize> = graphemes.iter().map(|(_, _, width)| *width).collect();
+        let marker = if ellipsis.symbol.width() <= max_width {
+            ellipsis.symbol
+        } else {
+            ""
+        };
+        let Some((head, tail)) = kept_ranges(&widths, max_width, marker.width(), ellipsis.position)
+        else {
+            return self.clone();
+        };
+
+        let mut spans = self.slice_spans(&graphemes[head.clone()]);
+        if !marker.is_empty() {
+            let style = self.spans[graphemes[head.end].0].style;
+            spans.push(Span::styled(marker.to_string(), style));
+        }
+        spans.extend(self.slice_spans(&graphemes[tail]));
+        Line {
+            spans,
+            alignment: self.alignment,
+        }
+    }
+
+    /// Returns the parts of the spans made of the given consecutive graphemes
+    fn slice_spans(&self, graphemes: &[(usize, Range<usize>, usize)]) -> Vec<Span<'a>> {
+        let mut spans: Vec<Span<'a>> = vec![];
+        let mut start = 0;
+        while start < graphemes.len() {
+            let span_index = graphemes[start].0;
+            let len = graphemes[start..]
+                .iter()
+                .take_while(|(i, _, _)| *i == span_index)
+                .count();
+            let range = graphemes[start].1.start..graphemes[start + len - 1].1.end;
+            let span = &self.spans[span_index];
+            let content = match &span.content {
+                Cow::Borrowed(content) => Cow::Borrowed(&content[range]),
+                Cow::Owned(content) => Cow::Owned(content[range].to_string()),
+            };
+            spans.push(Span::styled(content, span.style));
+            start += len;
+        }
+        spans
+    }
+
     /// Patches the style of each Span in an existing Line, adding modifiers from the given style.
     ///
     /// ## Examples
@@ -141,12 +229,37 @@ impl<'a> From<Spans<'a>> for Line<'a> {
     }
 }
 
+/// Renders the line on the first row of the area, aligned according to [`Line::alignment`] (on
+/// the left by default) and truncated at the width of the area.
+impl Widget for &Line<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let line_width = area.width.min(self.width() as u16);
+        let offset = match self.alignment {
+            Some(Alignment::Center) => (area.width - line_width) / 2,
+            Some(Alignment::Right) => area.width - line_width,
+            Some(Alignment::Left | Alignment::Justify) | None => 0,
+        };
+        buf.set_line(area.x + offset, area.y, self, area.width - offset);
+    }
+}
+
+impl Widget for Line<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        layout::Alignment,
+        buffer::Buffer,
+        layout::{Alignment, Rect},
         style::{Color, Modifier, Style},
-        text::{Line, Span, Spans},
+        text::{Ellipsis, EllipsisPosition, Line, Span, Spans},
+        widgets::Widget,
     };
 
     #[test]
@@ -248,4 +361,91 @@ mod tests {
         let line = Line::from("This is default");
         assert_eq!(None, line.alignment);
     }
+
+    #[test]
+    fn render_aligned() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Line::from("ab").render(Rect::new(0, 0, 7, 1), &mut buffer);
+        Line::from("ab")
+            .alignment(Alignment::Center)
+            .render(Rect::new(0, 1, 7, 1), &mut buffer);
+        Line::from("ab")
+            .alignment(Alignment::Right)
+            .render(Rect::new(0, 2, 7, 1), &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(vec!["ab     ", "  ab   ", "     ab"])
+        );
+    }
+
+    #[test]
+    fn render_truncated() {
+        let line = Line::from(vec![
+            Span::styled("ab", Style::default().fg(Color::Red)),
+            Span::raw("称号"),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        (&line).render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(vec!["ab称 "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Red));
+        assert_eq!(buffer, expected);
+
+        // a centered line wider than the area is truncated on the right
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        line.alignment(Alignment::Center)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, expected.crop(Rect::new(0, 0, 3, 1)));
+    }
+
+    #[test]
+    fn truncated() {
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![Span::styled("ab", red), Span::raw("称号cd")]);
+        assert_eq!(line.truncated(8, Ellipsis::end()), line);
+        assert_eq!(
+            line.truncated(5, Ellipsis::end()),
+            Line::from(vec![
+                Span::styled("ab", red),
+                Span::raw("称"),
+                Span::raw("…")
+            ])
+        );
+        // the wide grapheme is not split and the marker takes the style of the first removed
+        // grapheme
+        assert_eq!(
+            line.truncated(4, Ellipsis::end()),
+            Line::from(vec![Span::styled("ab", red), Span::raw("…")])
+        );
+        assert_eq!(
+            line.truncated(3, Ellipsis::start()),
+            Line::from(vec![Span::styled("…", red), Span::raw("cd")])
+        );
+        assert_eq!(
+            line.truncated(5, Ellipsis::middle()),
+            Line::from(vec![
+                Span::styled("ab", red),
+                Span::raw("…"),
+                Span::raw("cd")
+            ])
+        );
+        let ellipsis = Ellipsis {
+            symbol: "⋯⋯",
+            position: EllipsisPosition::End,
+        };
+        assert_eq!(
+            line.truncated(1, ellipsis),
+            Line::from(vec![Span::styled("a", red)])
+        );
+    }
+
+    #[test]
+    fn truncated_keeps_borrowed_content() {
+        let line = Line::from("Hello world").alignment(Alignment::Center);
+        let truncated = line.truncated(6, Ellipsis::end());
+        assert_eq!(truncated.alignment, Some(Alignment::Center));
+        assert!(matches!(
+            truncated.spans[0].content,
+            std::borrow::Cow::Borrowed("Hello")
+        ));
+    }
 }
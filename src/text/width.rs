@@ -0,0 +1,213 @@
+//! Configurable computation of the display width of strings.
+//!
+//! Terminals disagree on the width of some graphemes: East Asian characters of ambiguous width
+//! are displayed as wide by terminals configured for CJK locales, and the emoji sequences (e.g. a
+//! symbol followed by the emoji variation selector, or a ZWJ sequence) are displayed as a single
+//! wide symbol by most terminals, while older versions of [`unicode-width`] sum the width of
+//! their characters. The
+//! [`WidthStrategy`] set with [`set_width_strategy`] is used by the whole library to measure
+//! text, so that the layout matches what the terminal actually displays.
+//!
+//! [`unicode-width`]: https://docs.rs/unicode-width
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Bit of [`STRATEGY`] set when ambiguous characters are wide
+const AMBIGUOUS_AS_WIDE: u8 = 0b01;
+/// Bit of [`STRATEGY`] set when emoji sequences are wide
+const EMOJI_AS_WIDE: u8 = 0b10;
+
+/// The current width strategy, stored as bit flags so that it is cheap to read
+static STRATEGY: AtomicU8 = AtomicU8::new(0);
+
+/// How the display width of strings is computed.
+///
+/// The default strategy follows [`unicode-width`]: ambiguous characters are narrow and the width
+/// of a grapheme is the sum of the width of its characters.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::text::WidthStrategy;
+/// let strategy = WidthStrategy {
+///     ambiguous_as_wide: true,
+///     emoji_as_wide: true,
+/// };
+/// assert_eq!(strategy.str_width("±"), 2);
+/// assert_eq!(strategy.str_width("☺\u{FE0F}"), 2);
+/// assert_eq!(strategy.str_width("☺✓"), 2);
+/// assert_eq!(WidthStrategy::default().str_width("±☺"), 2);
+/// ```
+///
+/// [`unicode-width`]: https://docs.rs/unicode-width
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WidthStrategy {
+    /// Display the East Asian characters of ambiguous width (e.g. `±`, `°`, `①`) as wide
+    pub ambiguous_as_wide: bool,
+    /// Display the emoji sequences as wide: the symbols followed by the emoji variation selector
+    /// (e.g. `☺\u{FE0F}`), ZWJ sequences, flags, keycaps and skin tones. A symbol defaulting to
+    /// the text presentation (e.g. `☺`, `✓`, `⬅`) keeps its width.
+    pub emoji_as_wide: bool,
+}
+
+impl WidthStrategy {
+    fn from_bits(bits: u8) -> WidthStrategy {
+        WidthStrategy {
+            ambiguous_as_wide: bits & AMBIGUOUS_AS_WIDE != 0,
+            emoji_as_wide: bits & EMOJI_AS_WIDE != 0,
+        }
+    }
+
+    fn bits(self) -> u8 {
+        let mut bits = 0;
+        if self.ambiguous_as_wide {
+            bits |= AMBIGUOUS_AS_WIDE;
+        }
+        if self.emoji_as_wide {
+            bits |= EMOJI_AS_WIDE;
+        }
+        bits
+    }
+
+    /// Returns the display width of a string.
+    pub fn str_width(&self, s: &str) -> usize {
+        match (self.emoji_as_wide, self.ambiguous_as_wide) {
+            (false, false) => UnicodeWidthStr::width(s),
+            (false, true) => s.width_cjk(),
+            (true, _) => s.graphemes(true).map(|g| self.grapheme_width(g)).sum(),
+        }
+    }
+
+    /// Returns the display width of a single grapheme.
+    pub fn grapheme_width(&self, grapheme: &str) -> usize {
+        if self.emoji_as_wide && is_emoji(grapheme) {
+            2
+        } else if self.ambiguous_as_wide {
+            grapheme.width_cjk()
+        } else {
+            UnicodeWidthStr::width(grapheme)
+        }
+    }
+}
+
+/// Returns whether a grapheme is an emoji sequence that is not explicitly displayed with the
+/// text presentation
+fn is_emoji(grapheme: &str) -> bool {
+    // text variation selector
+    if grapheme.contains('\u{FE0E}') {
+        return false;
+    }
+    let is_regional_indicator = |c: char| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c);
+    let mut chars = grapheme.chars();
+    let is_flag = chars.next().map_or(false, is_regional_indicator)
+        && chars.next().map_or(false, is_regional_indicator);
+    // emoji variation selector
+    is_flag
+        || grapheme.contains('\u{FE0F}')
+        || grapheme.chars().any(is_pictographic)
+            && grapheme.chars().any(|c| {
+                // zero width joiner and skin tone modifiers
+                c == '\u{200D}' || ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+            })
+}
+
+/// Returns whether a character belongs to the supplementary blocks of pictographic symbols,
+/// which only hold emoji: a zero width joiner next to them forms a ZWJ sequence rather than a
+/// ligature of some script
+fn is_pictographic(c: char) -> bool {
+    ('\u{1F000}'..='\u{1FAFF}').contains(&c)
+}
+
+/// Sets the [`WidthStrategy`] used by the library to measure text.
+///
+/// The strategy is global to the process. It should be set once, before drawing anything, to
+/// match the terminal the application runs in.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::text::{set_width_strategy, Span, WidthStrategy};
+/// set_width_strategy(WidthStrategy {
+///     emoji_as_wide: true,
+///     ..WidthStrategy::default()
+/// });
+/// assert_eq!(Span::raw("☺\u{FE0F} ⚠\u{FE0F}").width(), 5);
+/// assert_eq!(Span::raw("☺ ✓").width(), 3);
+/// # set_width_strategy(WidthStrategy::default());
+/// ```
+pub fn set_width_strategy(strategy: WidthStrategy) {
+    STRATEGY.store(strategy.bits(), Ordering::Relaxed);
+}
+
+/// Returns the [`WidthStrategy`] used by the library to measure text.
+pub fn width_strategy() -> WidthStrategy {
+    WidthStrategy::from_bits(STRATEGY.load(Ordering::Relaxed))
+}
+
+/// Display width of a string according to the current [`WidthStrategy`].
+///
+/// This trait is a drop-in replacement of [`unicode_width::UnicodeWidthStr`] for widgets that
+/// should measure text the same way as the rest of the library.
+pub trait DisplayWidth {
+    /// Returns the display width of the string according to the current [`WidthStrategy`].
+    fn width(&self) -> usize;
+}
+
+impl DisplayWidth for str {
+    fn width(&self) -> usize {
+        width_strategy().str_width(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_strategy_matches_unicode_width() {
+        let strategy = WidthStrategy::default();
+        for s in ["abc", "称号", "±", "☺", "❤️", "👨‍👩‍👧", "🇫🇷", "1️⃣", "👍🏽"]
+        {
+            assert_eq!(strategy.str_width(s), UnicodeWidthStr::width(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn ambiguous_as_wide() {
+        let strategy = WidthStrategy {
+            ambiguous_as_wide: true,
+            ..WidthStrategy::default()
+        };
+        assert_eq!(strategy.str_width("a±°"), 5);
+        assert_eq!(strategy.str_width("称"), 2);
+        assert_eq!(strategy.str_width("☺"), 1);
+    }
+
+    #[test]
+    fn emoji_as_wide() {
+        let strategy = WidthStrategy {
+            emoji_as_wide: true,
+            ..WidthStrategy::default()
+        };
+        assert_eq!(strategy.str_width("☺a⚠"), 3);
+        assert_eq!(strategy.str_width("✓❯⬅"), 3);
+        assert_eq!(strategy.str_width("☺\u{FE0F}a⚠\u{FE0F}"), 5);
+        assert_eq!(strategy.str_width("☺\u{FE0E}"), 1);
+        assert_eq!(strategy.str_width("❤️"), 2);
+        assert_eq!(strategy.str_width("👨‍👩‍👧"), 2);
+        assert_eq!(strategy.str_width("🇫🇷"), 2);
+        assert_eq!(strategy.str_width("1️⃣"), 2);
+        assert_eq!(strategy.str_width("👍🏽a"), 3);
+        assert_eq!(strategy.str_width("±─→"), 3);
+    }
+
+    #[test]
+    fn strategy_bits_round_trip() {
+        for bits in 0..4 {
+            assert_eq!(WidthStrategy::from_bits(bits).bits(), bits);
+        }
+    }
+}
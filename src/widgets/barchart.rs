@@ -1,12 +1,11 @@
 use std::cmp::min;
 
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::Style,
+    style::{impl_styled, Style},
     symbols,
+    text::DisplayWidth,
     widgets::{Block, Widget},
 };
 
@@ -128,11 +127,11 @@ impl<'a> BarChart<'a> {
     }
 }
 
-impl<'a> Widget for BarChart<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
+impl Widget for &BarChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
 
-        let chart_area = match self.block.take() {
+        let chart_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -219,3 +218,11 @@ impl<'a> Widget for BarChart<'a> {
         }
     }
 }
+
+impl Widget for BarChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(BarChart);
@@ -5,7 +5,7 @@ use self::title::{Position, Title};
 use crate::{
     buffer::Buffer,
     layout::{Alignment, Rect},
-    style::Style,
+    style::{impl_styled, theme, Style},
     symbols::line,
     widgets::{Borders, Widget},
 };
@@ -326,7 +326,9 @@ impl<'a> Block<'a> {
     }
 
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
+        let theme = theme();
+        buf.set_style(area, theme.base.patch(self.style));
+        let border_style = theme.border.patch(self.border_style);
         let symbols = BorderType::line_symbols(self.border_type);
 
         // Sides
@@ -334,14 +336,14 @@ impl<'a> Block<'a> {
             for y in area.top()..area.bottom() {
                 buf.get_mut(area.left(), y)
                     .set_symbol(symbols.vertical)
-                    .set_style(self.border_style);
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::TOP) {
             for x in area.left()..area.right() {
                 buf.get_mut(x, area.top())
                     .set_symbol(symbols.horizontal)
-                    .set_style(self.border_style);
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::RIGHT) {
@@ -349,7 +351,7 @@ impl<'a> Block<'a> {
             for y in area.top()..area.bottom() {
                 buf.get_mut(x, y)
                     .set_symbol(symbols.vertical)
-                    .set_style(self.border_style);
+                    .set_style(border_style);
             }
         }
         if self.borders.intersects(Borders::BOTTOM) {
@@ -357,7 +359,7 @@ impl<'a> Block<'a> {
             for x in area.left()..area.right() {
                 buf.get_mut(x, y)
                     .set_symbol(symbols.horizontal)
-                    .set_style(self.border_style);
+                    .set_style(border_style);
             }
         }
 
@@ -365,22 +367,22 @@ impl<'a> Block<'a> {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
             buf.get_mut(area.right() - 1, area.bottom() - 1)
                 .set_symbol(symbols.bottom_right)
-                .set_style(self.border_style);
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
             buf.get_mut(area.right() - 1, area.top())
                 .set_symbol(symbols.top_right)
-                .set_style(self.border_style);
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
             buf.get_mut(area.left(), area.bottom() - 1)
                 .set_symbol(symbols.bottom_left)
-                .set_style(self.border_style);
+                .set_style(border_style);
         }
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
             buf.get_mut(area.left(), area.top())
                 .set_symbol(symbols.top_left)
-                .set_style(self.border_style);
+                .set_style(border_style);
         }
     }
 
@@ -393,8 +395,11 @@ impl<'a> Block<'a> {
     }
 
     fn title_filter(&self, title: &Title, alignment: Alignment, position: Position) -> bool {
-        title.alignment.unwrap_or(self.titles_alignment) == alignment
-            && title.position.unwrap_or(self.titles_position) == position
+        let title_alignment = match title.alignment.unwrap_or(self.titles_alignment) {
+            Alignment::Justify => Alignment::Left,
+            title_alignment => title_alignment,
+        };
+        title_alignment == alignment && title.position.unwrap_or(self.titles_position) == position
     }
 
     fn calculate_title_area_offsets(&self, area: Rect) -> (u16, u16, u16) {
@@ -420,11 +425,12 @@ impl<'a> Block<'a> {
                 let title_x = current_offset;
                 current_offset += title.content.width() as u16 + 1;
 
-                buf.set_line(
+                self.render_title(
                     title_x + area.left(),
                     self.get_title_y(position, area),
-                    &title.content,
+                    title,
                     title_area_width,
+                    buf,
                 );
             });
     }
@@ -446,11 +452,12 @@ impl<'a> Block<'a> {
             let title_x = current_offset;
             current_offset += title.content.width() as u16 + 1;
 
-            buf.set_line(
+            self.render_title(
                 title_x + area.left(),
                 self.get_title_y(position, area),
-                &title.content,
+                title,
                 title_area_width,
+                buf,
             );
         });
     }
@@ -467,15 +474,25 @@ impl<'a> Block<'a> {
                 current_offset += title.content.width() as u16 + 1;
                 let title_x = current_offset - 1; // First element isn't spaced
 
-                buf.set_line(
+                self.render_title(
                     area.width.saturating_sub(title_x) + area.left(),
                     self.get_title_y(position, area),
-                    &title.content,
+                    title,
                     title_area_width,
+                    buf,
                 );
             });
     }
 
+    /// Renders a title with the title style of the theme beneath it
+    fn render_title(&self, x: u16, y: u16, title: &Title, width: u16, buf: &mut Buffer) {
+        let style = theme().title;
+        let title_width = (title.content.width() as u16).min(width);
+        let title_area = Rect::new(x, y, title_width, 1).intersection(*buf.area());
+        buf.set_style(title_area, style);
+        buf.set_line(x, y, &title.content, width);
+    }
+
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
         // Note: the order in which these functions are called define the overlapping behavior
         self.render_right_titles(position, area, buf);
@@ -489,7 +506,7 @@ impl<'a> Block<'a> {
     }
 }
 
-impl<'a> Widget for Block<'a> {
+impl Widget for &Block<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if area.area() == 0 {
             return;
@@ -499,6 +516,14 @@ impl<'a> Widget for Block<'a> {
     }
 }
 
+impl Widget for Block<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(Block);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,12 +441,12 @@ where
     }
 }
 
-impl<'a, F> Widget for Canvas<'a, F>
+impl<F> Widget for &Canvas<'_, F>
 where
     F: Fn(&mut Context),
 {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        let canvas_area = match self.block.take() {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let canvas_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -459,7 +459,9 @@ where
 
         let width = canvas_area.width as usize;
 
-        let Some(ref painter) = self.painter else { return };
+        let Some(ref painter) = self.painter else {
+            return;
+        };
 
         // Create a blank context that match the size of the canvas
         let mut ctx = Context::new(
@@ -514,6 +516,15 @@ where
     }
 }
 
+impl<F> Widget for Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -1,13 +1,11 @@
 use std::{borrow::Cow, cmp::max};
 
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Rect},
-    style::{Color, Style},
+    style::{impl_styled, Color, Style},
     symbols,
-    text::{Line as TextLine, Span},
+    text::{DisplayWidth, Line as TextLine, Span},
     widgets::{
         canvas::{Canvas, Line, Points},
         Block, Borders, Widget,
@@ -363,7 +361,7 @@ impl<'a> Chart<'a> {
         if let Some(first_x_label) = self.x_axis.labels.as_ref().and_then(|labels| labels.get(0)) {
             let first_label_width = first_x_label.content.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
-                Alignment::Left => {
+                Alignment::Left | Alignment::Justify => {
                     // The last character of the label should be below the Y-Axis when it exists,
                     // not on its left
                     let y_axis_offset = u16::from(has_y_axis);
@@ -379,7 +377,7 @@ impl<'a> Chart<'a> {
     }
 
     fn render_x_labels(
-        &mut self,
+        &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
         chart_area: Rect,
@@ -403,7 +401,7 @@ impl<'a> Chart<'a> {
         );
 
         let label_alignment = match self.x_axis.labels_alignment {
-            Alignment::Left => Alignment::Right,
+            Alignment::Left | Alignment::Justify => Alignment::Right,
             Alignment::Center => Alignment::Center,
             Alignment::Right => Alignment::Left,
         };
@@ -434,7 +432,7 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) -> Rect {
         let (min_x, max_x) = match self.x_axis.labels_alignment {
-            Alignment::Left => (chart_area.left(), graph_area.left()),
+            Alignment::Left | Alignment::Justify => (chart_area.left(), graph_area.left()),
             Alignment::Center => (
                 chart_area.left(),
                 graph_area.left() + max_width_after_y_axis.min(label_width),
@@ -453,7 +451,7 @@ impl<'a> Chart<'a> {
         let bounded_label_width = label_area.width.min(label_width);
 
         let x = match alignment {
-            Alignment::Left => label_area.left(),
+            Alignment::Left | Alignment::Justify => label_area.left(),
             Alignment::Center => label_area.left() + label_area.width / 2 - bounded_label_width / 2,
             Alignment::Right => label_area.right() - bounded_label_width,
         };
@@ -462,7 +460,7 @@ impl<'a> Chart<'a> {
     }
 
     fn render_y_labels(
-        &mut self,
+        &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
         chart_area: Rect,
@@ -486,8 +484,8 @@ impl<'a> Chart<'a> {
     }
 }
 
-impl<'a> Widget for Chart<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
+impl Widget for &Chart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
         if area.area() == 0 {
             return;
         }
@@ -497,7 +495,7 @@ impl<'a> Widget for Chart<'a> {
         // axis names).
         let original_style = buf.get(area.left(), area.top()).style();
 
-        let chart_area = match self.block.take() {
+        let chart_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -581,7 +579,7 @@ impl<'a> Widget for Chart<'a> {
         }
 
         if let Some((x, y)) = layout.title_x {
-            let title = self.x_axis.title.unwrap();
+            let title = self.x_axis.title.as_ref().unwrap();
             let width = graph_area.right().saturating_sub(x);
             buf.set_style(
                 Rect {
@@ -592,11 +590,11 @@ impl<'a> Widget for Chart<'a> {
                 },
                 original_style,
             );
-            buf.set_line(x, y, &title, width);
+            buf.set_line(x, y, title, width);
         }
 
         if let Some((x, y)) = layout.title_y {
-            let title = self.y_axis.title.unwrap();
+            let title = self.y_axis.title.as_ref().unwrap();
             let width = graph_area.right().saturating_sub(x);
             buf.set_style(
                 Rect {
@@ -607,11 +605,19 @@ impl<'a> Widget for Chart<'a> {
                 },
                 original_style,
             );
-            buf.set_line(x, y, &title, width);
+            buf.set_line(x, y, title, width);
         }
     }
 }
 
+impl Widget for Chart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(Axis, Dataset, Chart);
+
 #[cfg(test)]
 mod tests {
     use super::*;
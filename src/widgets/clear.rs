@@ -26,7 +26,7 @@ use crate::{buffer::Buffer, layout::Rect, widgets::Widget};
 #[derive(Debug, Clone)]
 pub struct Clear;
 
-impl Widget for Clear {
+impl Widget for &Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
         for x in area.left()..area.right() {
             for y in area.top()..area.bottom() {
@@ -35,3 +35,9 @@ impl Widget for Clear {
         }
     }
 }
+
+impl Widget for Clear {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{impl_styled, theme, Color, Style},
     symbols,
     text::{Line, Span},
     widgets::{Block, Widget},
@@ -91,10 +91,12 @@ impl<'a> Gauge<'a> {
     }
 }
 
-impl<'a> Widget for Gauge<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let gauge_area = match self.block.take() {
+impl Widget for &Gauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = theme();
+        buf.set_style(area, theme.base.patch(self.style));
+        let gauge_style = theme.highlight.patch(self.gauge_style);
+        let gauge_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -102,16 +104,21 @@ impl<'a> Widget for Gauge<'a> {
             }
             None => area,
         };
-        buf.set_style(gauge_area, self.gauge_style);
+        buf.set_style(gauge_area, gauge_style);
         if gauge_area.height < 1 {
             return;
         }
 
         // compute label value and its position
         // label is put at the center of the gauge_area
-        let label = {
-            let pct = f64::round(self.ratio * 100.0);
-            self.label.unwrap_or_else(|| Span::from(format!("{pct}%")))
+        let default_label;
+        let label = match &self.label {
+            Some(label) => label,
+            None => {
+                let pct = f64::round(self.ratio * 100.0);
+                default_label = Span::from(format!("{pct}%"));
+                &default_label
+            }
         };
         let clamped_label_width = gauge_area.width.min(label.width() as u16);
         let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
@@ -130,15 +137,15 @@ impl<'a> Widget for Gauge<'a> {
                 let cell = buf.get_mut(x, y);
                 if self.use_unicode {
                     cell.set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                        .set_fg(gauge_style.fg.unwrap_or(Color::Reset))
+                        .set_bg(gauge_style.bg.unwrap_or(Color::Reset));
                 } else {
                     // spaces are needed to apply the background styling.
                     // note that the background and foreground colors are swapped
                     // otherwise the gauge will be inverted
                     cell.set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                        .set_fg(gauge_style.bg.unwrap_or(Color::Reset))
+                        .set_bg(gauge_style.fg.unwrap_or(Color::Reset));
                 }
             }
             if self.use_unicode && self.ratio < 1.0 {
@@ -147,7 +154,13 @@ impl<'a> Widget for Gauge<'a> {
             }
         }
         // render the label
-        buf.set_span(label_col, label_row, &label, clamped_label_width);
+        buf.set_span(label_col, label_row, label, clamped_label_width);
+    }
+}
+
+impl Widget for Gauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
     }
 }
 
@@ -240,10 +253,12 @@ impl<'a> LineGauge<'a> {
     }
 }
 
-impl<'a> Widget for LineGauge<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let gauge_area = match self.block.take() {
+impl Widget for &LineGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = theme();
+        buf.set_style(area, theme.base.patch(self.style));
+        let gauge_style = theme.highlight.patch(self.gauge_style);
+        let gauge_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -256,16 +271,15 @@ impl<'a> Widget for LineGauge<'a> {
             return;
         }
 
-        let ratio = self.ratio;
-        let label = self
-            .label
-            .unwrap_or_else(move || Line::from(format!("{:.0}%", ratio * 100.0)));
-        let (col, row) = buf.set_line(
-            gauge_area.left(),
-            gauge_area.top(),
-            &label,
-            gauge_area.width,
-        );
+        let default_label;
+        let label = match &self.label {
+            Some(label) => label,
+            None => {
+                default_label = Line::from(format!("{:.0}%", self.ratio * 100.0));
+                &default_label
+            }
+        };
+        let (col, row) = buf.set_line(gauge_area.left(), gauge_area.top(), label, gauge_area.width);
         let start = col + 1;
         if start >= gauge_area.right() {
             return;
@@ -277,25 +291,33 @@ impl<'a> Widget for LineGauge<'a> {
             buf.get_mut(col, row)
                 .set_symbol(self.line_set.horizontal)
                 .set_style(Style {
-                    fg: self.gauge_style.fg,
+                    fg: gauge_style.fg,
                     bg: None,
-                    add_modifier: self.gauge_style.add_modifier,
-                    sub_modifier: self.gauge_style.sub_modifier,
+                    add_modifier: gauge_style.add_modifier,
+                    sub_modifier: gauge_style.sub_modifier,
                 });
         }
         for col in end..gauge_area.right() {
             buf.get_mut(col, row)
                 .set_symbol(self.line_set.horizontal)
                 .set_style(Style {
-                    fg: self.gauge_style.bg,
+                    fg: gauge_style.bg,
                     bg: None,
-                    add_modifier: self.gauge_style.add_modifier,
-                    sub_modifier: self.gauge_style.sub_modifier,
+                    add_modifier: gauge_style.add_modifier,
+                    sub_modifier: gauge_style.sub_modifier,
                 });
         }
     }
 }
 
+impl Widget for LineGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(Gauge, LineGauge);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,8 @@
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     buffer::Buffer,
     layout::{Corner, Rect},
-    style::Style,
-    text::Text,
+    style::{impl_styled, theme, Style},
+    text::{DisplayWidth, Ellipsis, Text},
     widgets::{Block, StatefulWidget, Widget},
 };
 
@@ -103,6 +101,8 @@ pub struct List<'a> {
     highlight_symbol: Option<&'a str>,
     /// Whether to repeat the highlight symbol for each line of the selected item
     repeat_highlight_symbol: bool,
+    /// How the lines wider than the list are truncated
+    ellipsis: Option<Ellipsis<'a>>,
 }
 
 impl<'a> List<'a> {
@@ -118,6 +118,7 @@ impl<'a> List<'a> {
             highlight_style: Style::default(),
             highlight_symbol: None,
             repeat_highlight_symbol: false,
+            ellipsis: None,
         }
     }
 
@@ -151,6 +152,12 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Marks the lines of the items that do not fit in the list with an [`Ellipsis`].
+    pub fn ellipsis(mut self, ellipsis: Ellipsis<'a>) -> List<'a> {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
+
     pub fn len(&self) -> usize {
         self.items.len()
     }
@@ -198,12 +205,15 @@ impl<'a> List<'a> {
     }
 }
 
-impl<'a> StatefulWidget for List<'a> {
+impl StatefulWidget for &List<'_> {
     type State = ListState;
 
-    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
-        let list_area = match self.block.take() {
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = theme();
+        let style = theme.base.patch(self.style);
+        let highlight_style = theme.selection.patch(self.highlight_style);
+        buf.set_style(area, style);
+        let list_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -231,7 +241,7 @@ impl<'a> StatefulWidget for List<'a> {
         let has_selection = state.selected.is_some();
         for (i, item) in self
             .items
-            .iter_mut()
+            .iter()
             .enumerate()
             .skip(state.offset)
             .take(end - start)
@@ -250,7 +260,7 @@ impl<'a> StatefulWidget for List<'a> {
                 width: list_area.width,
                 height: item.height() as u16,
             };
-            let item_style = self.style.patch(item.style);
+            let item_style = style.patch(item.style);
             buf.set_style(area, item_style);
 
             let is_selected = state.selected.map_or(false, |s| s == i);
@@ -275,22 +285,43 @@ impl<'a> StatefulWidget for List<'a> {
                 } else {
                     (x, list_area.width)
                 };
-                buf.set_line(elem_x, y + j as u16, line, max_element_width);
+                if let Some(ellipsis) = self.ellipsis {
+                    let line = line.truncated(max_element_width as usize, ellipsis);
+                    buf.set_line(elem_x, y + j as u16, &line, max_element_width);
+                } else {
+                    buf.set_line(elem_x, y + j as u16, line, max_element_width);
+                }
             }
             if is_selected {
-                buf.set_style(area, self.highlight_style);
+                buf.set_style(area, highlight_style);
             }
         }
     }
 }
 
-impl<'a> Widget for List<'a> {
+impl StatefulWidget for List<'_> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl Widget for &List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let mut state = ListState::default();
         StatefulWidget::render(self, area, buf, &mut state);
     }
 }
 
+impl Widget for List<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl_styled!(ListItem, List);
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
@@ -409,6 +440,21 @@ mod tests {
         assert_eq!(item.width(), 9);
     }
 
+    #[test]
+    fn test_list_render_by_reference() {
+        let list = List::new(list_items(vec!["Item 0", "Item 1", "Item 2"])).highlight_symbol(">>");
+        let mut state = ListState::default();
+        state.select(Some(2));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+        assert_buffer_eq!(buffer, Buffer::with_lines(vec!["  Item 1  ", ">>Item 2  "]));
+
+        // the same list is rendered again with the updated state
+        state.select(Some(0));
+        StatefulWidget::render(&list, buffer.area, &mut buffer, &mut state);
+        assert_buffer_eq!(buffer, Buffer::with_lines(vec![">>Item 0  ", "  Item 1  "]));
+    }
+
     /// helper method to take a vector of strings and return a vector of list items
     fn list_items(items: Vec<&str>) -> Vec<ListItem> {
         items.iter().map(|i| ListItem::new(i.to_string())).collect()
@@ -1,7 +1,8 @@
 //! `widgets` is a collection of types that implement [`Widget`] or [`StatefulWidget`] or both.
 //!
-//! All widgets are implemented using the builder pattern and are consumable objects. They are not
-//! meant to be stored but used as *commands* to draw common figures in the UI.
+//! All widgets are implemented using the builder pattern and are consumable objects. They are
+//! mostly used as *commands* to draw common figures in the UI, but can also be stored and rendered
+//! by reference, or as trait objects with [`WidgetRef`] and [`StatefulWidgetRef`].
 //!
 //! The available widgets are:
 //! - [`Block`]
@@ -43,7 +44,7 @@ pub use self::{
     clear::Clear,
     gauge::{Gauge, LineGauge},
     list::{List, ListItem, ListState},
-    paragraph::{Paragraph, Wrap},
+    paragraph::{Paragraph, ParagraphState, Wrap},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
     sparkline::{RenderDirection, Sparkline},
     table::{Cell, Row, Table, TableState},
@@ -101,6 +102,24 @@ impl Debug for Borders {
 }
 
 /// Base requirements for a Widget
+///
+/// The built-in widgets also implement `Widget` for a reference to themselves (and
+/// `StatefulWidget` for the stateful ones), so that a widget kept in the application state can be
+/// rendered on every frame without being rebuilt or cloned.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::layout::Rect;
+/// # use ratatui::widgets::{Paragraph, Widget};
+/// let paragraph = Paragraph::new("Hello");
+/// let area = Rect::new(0, 0, 5, 1);
+/// let mut buf = Buffer::empty(area);
+/// (&paragraph).render(area, &mut buf);
+/// // the paragraph can still be used
+/// paragraph.render(area, &mut buf);
+/// ```
 pub trait Widget {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom widget.
@@ -223,6 +242,183 @@ pub trait StatefulWidget {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
 }
 
+/// An object-safe version of [`Widget`], rendering the widget by reference.
+///
+/// [`Widget`] cannot be used as a trait object because [`Widget::render`] takes `self`. This
+/// trait can, so widgets of different types can be stored together, e.g. in a
+/// `Vec<Box<dyn WidgetRef>>`, and rendered with [`Frame::render_widget_ref`]. A boxed
+/// `WidgetRef` also implements [`Widget`].
+///
+/// `WidgetRef` is implemented for every type `W` such that `&W` implements [`Widget`], which
+/// includes all the built-in widgets. Custom widgets get it by implementing [`Widget`] for a
+/// reference to themselves. A widget implementing [`Widget`] by value only can be wrapped in a
+/// [`ClonedWidget`] instead, which renders a clone of the widget.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::layout::Rect;
+/// # use ratatui::widgets::{Block, Borders, Paragraph, WidgetRef};
+/// let widgets: Vec<Box<dyn WidgetRef>> = vec![
+///     Box::new(Block::default().borders(Borders::ALL)),
+///     Box::new(Paragraph::new("Hi")),
+/// ];
+/// let area = Rect::new(0, 0, 4, 3);
+/// let mut buf = Buffer::empty(area);
+/// for widget in &widgets {
+///     widget.render_ref(area, &mut buf);
+/// }
+/// assert_eq!(buf, Buffer::with_lines(vec!["Hi─┐", "│  │", "└──┘"]));
+/// ```
+///
+/// [`Frame::render_widget_ref`]: crate::Frame::render_widget_ref
+pub trait WidgetRef {
+    /// Draws the current state of the widget in the given buffer.
+    fn render_ref(&self, area: Rect, buf: &mut Buffer);
+}
+
+impl<W> WidgetRef for W
+where
+    for<'a> &'a W: Widget,
+{
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.render(area, buf);
+    }
+}
+
+impl<W: WidgetRef + ?Sized> Widget for &Box<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.as_ref().render_ref(area, buf);
+    }
+}
+
+impl<W: WidgetRef + ?Sized> Widget for Box<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.as_ref().render_ref(area, buf);
+    }
+}
+
+/// An object-safe version of [`StatefulWidget`], rendering the widget by reference.
+///
+/// See [`WidgetRef`] for more details. As the state type is part of the trait object type, the
+/// widgets of a collection must share the same state, e.g. `Box<dyn StatefulWidgetRef<State =
+/// ListState>>`.
+pub trait StatefulWidgetRef {
+    /// The state of the widget, kept between the draw calls.
+    type State;
+    /// Draws the current state of the widget in the given buffer, updating its state.
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
+}
+
+impl<W, S> StatefulWidgetRef for W
+where
+    for<'a> &'a W: StatefulWidget<State = S>,
+{
+    type State = S;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut S) {
+        self.render(area, buf, state);
+    }
+}
+
+impl<W: StatefulWidgetRef + ?Sized> StatefulWidget for &Box<W> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.as_ref().render_ref(area, buf, state);
+    }
+}
+
+impl<W: StatefulWidgetRef + ?Sized> StatefulWidget for Box<W> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.as_ref().render_ref(area, buf, state);
+    }
+}
+
+/// Adapts a widget implementing [`Widget`] or [`StatefulWidget`] by value into a [`WidgetRef`] or
+/// a [`StatefulWidgetRef`], by rendering a clone of the widget each time.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::layout::Rect;
+/// # use ratatui::widgets::{ClonedWidget, Widget, WidgetRef};
+/// #[derive(Clone)]
+/// struct Greeting;
+///
+/// impl Widget for Greeting {
+///     fn render(self, area: Rect, buf: &mut Buffer) {
+///         buf.set_string(area.x, area.y, "Hi", Default::default());
+///     }
+/// }
+///
+/// let widgets: Vec<Box<dyn WidgetRef>> = vec![Box::new(ClonedWidget(Greeting))];
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClonedWidget<W>(pub W);
+
+impl<W: Widget + Clone> Widget for &ClonedWidget<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.0.clone().render(area, buf);
+    }
+}
+
+impl<W: StatefulWidget + Clone> StatefulWidget for &ClonedWidget<W> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.0.clone().render(area, buf, state);
+    }
+}
+
+/// Renders a [`Widget`] into a new [`Buffer`] of the given size, starting at `(0, 0)`.
+///
+/// The returned buffer can be kept around and drawn again with [`Frame::render_buffer`] as long as
+/// the data displayed by the widget does not change, which avoids rendering expensive widgets
+/// (charts, maps...) on every frame. It can also be used to measure the output of a widget.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::widgets::{render_offscreen, Paragraph};
+/// let buffer = render_offscreen(Paragraph::new("Hello"), 7, 1);
+/// assert_eq!(buffer, Buffer::with_lines(vec!["Hello  "]));
+/// ```
+///
+/// [`Frame::render_buffer`]: crate::Frame::render_buffer
+pub fn render_offscreen<W>(widget: W, width: u16, height: u16) -> Buffer
+where
+    W: Widget,
+{
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    widget.render(area, &mut buffer);
+    buffer
+}
+
+/// Renders a [`StatefulWidget`] into a new [`Buffer`] of the given size, starting at `(0, 0)`.
+///
+/// See [`render_offscreen`] for more details.
+pub fn render_stateful_offscreen<W>(
+    widget: W,
+    width: u16,
+    height: u16,
+    state: &mut W::State,
+) -> Buffer
+where
+    W: StatefulWidget,
+{
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    widget.render(area, &mut buffer, state);
+    buffer
+}
+
 /// Macro that constructs and returns a [`Borders`] object from TOP, BOTTOM, LEFT, RIGHT, NONE, and
 /// ALL. Internally it creates an empty `Borders` object and then inserts each bit flag specified
 /// into it using `Borders::insert()`.
@@ -280,4 +476,79 @@ mod tests {
             "TOP | BOTTOM"
         );
     }
+
+    /// A custom widget rendered by reference
+    struct Fill(char);
+
+    impl Widget for &Fill {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    buf.get_mut(x, y).set_char(self.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn boxed_widgets() {
+        let widgets: Vec<Box<dyn WidgetRef>> = vec![
+            Box::new(Fill('x')),
+            Box::new(Block::default().borders(Borders::LEFT)),
+        ];
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        for widget in &widgets {
+            widget.render(area, &mut buf);
+        }
+        assert_eq!(buf, Buffer::with_lines(vec!["│x", "│x"]));
+
+        let boxed: Box<dyn WidgetRef> = Box::new(Fill('y'));
+        assert_eq!(render_offscreen(boxed, 1, 1), Buffer::with_lines(vec!["y"]));
+    }
+
+    #[test]
+    fn boxed_stateful_widgets() {
+        let list: Box<dyn StatefulWidgetRef<State = ListState>> =
+            Box::new(List::new(vec![ListItem::new("a"), ListItem::new("b")]));
+        let mut state = ListState::default();
+        state.select(Some(1));
+        let buf = render_stateful_offscreen(&list, 1, 1, &mut state);
+        assert_eq!(buf, Buffer::with_lines(vec!["b"]));
+        assert_eq!(state.offset(), 1);
+    }
+
+    /// A custom widget rendered by value
+    #[derive(Clone)]
+    struct Letter(char);
+
+    impl Widget for Letter {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            buf.get_mut(area.x, area.y).set_char(self.0);
+        }
+    }
+
+    impl StatefulWidget for Letter {
+        type State = usize;
+
+        fn render(self, area: Rect, buf: &mut Buffer, state: &mut usize) {
+            *state += 1;
+            Widget::render(self, area, buf);
+        }
+    }
+
+    #[test]
+    fn cloned_widgets() {
+        let widget: Box<dyn WidgetRef> = Box::new(ClonedWidget(Letter('a')));
+        assert_eq!(
+            render_offscreen(&widget, 1, 1),
+            Buffer::with_lines(vec!["a"])
+        );
+
+        let widget: Box<dyn StatefulWidgetRef<State = usize>> = Box::new(ClonedWidget(Letter('b')));
+        let mut state = 0;
+        let buf = render_stateful_offscreen(&widget, 1, 1, &mut state);
+        assert_eq!(buf, Buffer::with_lines(vec!["b"]));
+        assert_eq!(state, 1);
+    }
 }
@@ -1,13 +1,13 @@
-use unicode_width::UnicodeWidthStr;
+use std::iter;
 
 use crate::{
     buffer::Buffer,
     layout::{Alignment, Rect},
-    style::Style,
-    text::{StyledGrapheme, Text},
+    style::{impl_styled, Style},
+    text::{DisplayWidth, Ellipsis, StyledGrapheme, Text},
     widgets::{
-        reflow::{LineComposer, LineTruncator, WordWrapper},
-        Block, Widget,
+        reflow::{justify_padding, LineComposer, LineTruncator, WordWrapper},
+        Block, StatefulWidget, Widget,
     },
 };
 
@@ -15,7 +15,7 @@ fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment)
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
         Alignment::Right => text_area_width.saturating_sub(line_width),
-        Alignment::Left => 0,
+        Alignment::Left | Alignment::Justify => 0,
     }
 }
 
@@ -56,6 +56,12 @@ pub struct Paragraph<'a> {
     scroll: (u16, u16),
     /// Alignment of the text
     alignment: Alignment,
+    /// Marker of the words split in two when wrapping
+    hyphen: Option<&'a str>,
+    /// Whether the wrapped lines keep the indentation of their line
+    keep_indentation: bool,
+    /// How the lines are truncated when they are not wrapped
+    ellipsis: Option<Ellipsis<'a>>,
 }
 
 /// Describes how to wrap text across lines.
@@ -91,6 +97,128 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// The scroll state of a [`Paragraph`] rendered as a [`StatefulWidget`], e.g. for a log pane.
+///
+/// The state keeps the vertical and horizontal offsets of the text, which are clamped against
+/// the laid out text on each render. In follow tail mode, the paragraph sticks to the bottom of
+/// the text, so that the new lines are visible as they are added, until the user scrolls up. It
+/// sticks to the bottom again once scrolled back down to the last line.
+///
+/// # Examples
+///
+/// ```
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::widgets::{render_stateful_offscreen, Paragraph, ParagraphState};
+/// let mut state = ParagraphState::default().with_follow_tail(true);
+/// let logs = Paragraph::new("one\ntwo\nthree");
+/// let buffer = render_stateful_offscreen(&logs, 5, 2, &mut state);
+/// assert_eq!(buffer, Buffer::with_lines(vec!["two  ", "three"]));
+///
+/// state.scroll_up(1);
+/// let logs = Paragraph::new("one\ntwo\nthree\nfour");
+/// let buffer = render_stateful_offscreen(&logs, 5, 2, &mut state);
+/// assert_eq!(buffer, Buffer::with_lines(vec!["one  ", "two  "]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParagraphState {
+    /// Vertical and horizontal offsets, as in [`Paragraph::scroll`]
+    offset: (u16, u16),
+    /// Whether to stick to the bottom of the text
+    follow_tail: bool,
+    /// Whether the user scrolled up, away from the bottom of the text
+    scrolled_up: bool,
+}
+
+impl ParagraphState {
+    /// Returns the `(y, x)` offsets of the text, as clamped by the last render.
+    pub fn offset(&self) -> (u16, u16) {
+        self.offset
+    }
+
+    /// Sets the `(y, x)` offsets of the text and returns the modified ParagraphState, see
+    /// [`ParagraphState::set_offset`].
+    pub fn with_offset(mut self, offset: (u16, u16)) -> Self {
+        self.set_offset(offset);
+        self
+    }
+
+    /// Sets the `(y, x)` offsets of the text, which stops following the tail of the text until
+    /// the last line is reached again.
+    pub fn set_offset(&mut self, offset: (u16, u16)) {
+        self.offset = offset;
+        self.scrolled_up = true;
+    }
+
+    /// Enables or disables the follow tail mode and returns the modified ParagraphState.
+    pub fn with_follow_tail(mut self, follow_tail: bool) -> Self {
+        self.set_follow_tail(follow_tail);
+        self
+    }
+
+    /// Enables or disables the follow tail mode. Enabling it scrolls to the bottom of the text.
+    pub fn set_follow_tail(&mut self, follow_tail: bool) {
+        self.follow_tail = follow_tail;
+        self.scrolled_up = false;
+    }
+
+    /// Returns whether the follow tail mode is enabled and the user did not scroll up.
+    pub fn is_following_tail(&self) -> bool {
+        self.follow_tail && !self.scrolled_up
+    }
+
+    /// Scrolls up by the given number of lines, which stops following the tail of the text.
+    pub fn scroll_up(&mut self, lines: u16) {
+        if lines > 0 {
+            self.offset.0 = self.offset.0.saturating_sub(lines);
+            self.scrolled_up = true;
+        }
+    }
+
+    /// Scrolls down by the given number of lines. Scrolling down to the last line resumes
+    /// following the tail of the text, even when the whole text fits in the area.
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.offset.0 = self.offset.0.saturating_add(lines);
+    }
+
+    /// Scrolls left by the given number of columns.
+    pub fn scroll_left(&mut self, columns: u16) {
+        self.offset.1 = self.offset.1.saturating_sub(columns);
+    }
+
+    /// Scrolls right by the given number of columns. The offset is clamped on the next render,
+    /// and ignored when the text is wrapped.
+    pub fn scroll_right(&mut self, columns: u16) {
+        self.offset.1 = self.offset.1.saturating_add(columns);
+    }
+
+    /// Scrolls to the first line of the text, which stops following the tail of the text.
+    pub fn scroll_to_top(&mut self) {
+        self.offset.0 = 0;
+        self.scrolled_up = true;
+    }
+
+    /// Scrolls to the last line of the text, which resumes following the tail of the text.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset.0 = u16::MAX;
+        self.scrolled_up = false;
+    }
+
+    /// Clamps the offsets to the given maximums, or sticks to the bottom when following the tail
+    fn clamp(&mut self, max_y: u16, max_x: u16) {
+        if self.is_following_tail() {
+            self.offset.0 = max_y;
+        } else {
+            // Only an offset scrolled down to the last line resumes following the tail, not the
+            // top of a text fitting in the area, which would undo scrolling it up
+            if self.offset.0 >= max_y && self.offset.0 > 0 {
+                self.scrolled_up = false;
+            }
+            self.offset.0 = self.offset.0.min(max_y);
+        }
+        self.offset.1 = self.offset.1.min(max_x);
+    }
+}
+
 impl<'a> Paragraph<'a> {
     pub fn new<T>(text: T) -> Paragraph<'a>
     where
@@ -103,6 +231,9 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: (0, 0),
             alignment: Alignment::Left,
+            hyphen: None,
+            keep_indentation: false,
+            ellipsis: None,
         }
     }
 
@@ -130,54 +261,212 @@ impl<'a> Paragraph<'a> {
         self.alignment = alignment;
         self
     }
-}
 
-impl<'a> Widget for Paragraph<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let text_area = match self.block.take() {
-            Some(b) => {
-                let inner_area = b.inner(area);
-                b.render(area, buf);
-                inner_area
-            }
-            None => area,
+    /// Sets the marker, e.g. `"-"`, appended to a line when wrapping splits a word which does not
+    /// fit in a line.
+    ///
+    /// This only applies when [`Paragraph::wrap`] is set. The lines are preferably broken between
+    /// words, so only the words longer than a line are split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::widgets::{render_offscreen, Paragraph, Wrap};
+    /// let paragraph = Paragraph::new("Supercalifragilistic")
+    ///     .wrap(Wrap { trim: true })
+    ///     .hyphenation("-");
+    /// let buffer = render_offscreen(paragraph, 8, 3);
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["Superca-", "lifragi-", "listic  "]));
+    /// ```
+    pub fn hyphenation(mut self, marker: &'a str) -> Paragraph<'a> {
+        self.hyphen = Some(marker);
+        self
+    }
+
+    /// Whether the continuation lines of a wrapped line start with the same indentation (leading
+    /// whitespace) as the line, e.g. for bullet points or code.
+    ///
+    /// This only applies when [`Paragraph::wrap`] is set. The indentation of a line is kept even
+    /// when the whitespace is trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::widgets::{render_offscreen, Paragraph, Wrap};
+    /// let paragraph = Paragraph::new("  - a long point")
+    ///     .wrap(Wrap { trim: true })
+    ///     .keep_indentation(true);
+    /// let buffer = render_offscreen(paragraph, 10, 2);
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["  - a long", "  point   "]));
+    /// ```
+    pub fn keep_indentation(mut self, keep_indentation: bool) -> Paragraph<'a> {
+        self.keep_indentation = keep_indentation;
+        self
+    }
+
+    /// Marks the lines that do not fit in the width of the paragraph with an [`Ellipsis`],
+    /// instead of cutting them silently.
+    ///
+    /// This only applies when [`Paragraph::wrap`] is not set, and is ignored while the text is
+    /// scrolled horizontally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::buffer::Buffer;
+    /// # use ratatui::text::Ellipsis;
+    /// # use ratatui::widgets::{render_offscreen, Paragraph};
+    /// let paragraph = Paragraph::new("~/projects/app/src/main.rs").ellipsis(Ellipsis::middle());
+    /// let buffer = render_offscreen(paragraph, 12, 1);
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["~/proj…in.rs"]));
+    /// ```
+    pub fn ellipsis(mut self, ellipsis: Ellipsis<'a>) -> Paragraph<'a> {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
+
+    /// Returns the number of lines the text takes once laid out in an area of the given width,
+    /// i.e. after wrapping when [`Paragraph::wrap`] is set.
+    ///
+    /// The width is the one of the whole area given to the paragraph: the borders and padding of
+    /// the block are taken into account, but the lines of the block are not counted. This is the
+    /// length of the content to give to a vertical [`Scrollbar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::widgets::{Block, Borders, Paragraph, ScrollbarState, Wrap};
+    /// let paragraph = Paragraph::new("Hello, world!")
+    ///     .block(Block::default().borders(Borders::ALL))
+    ///     .wrap(Wrap { trim: true });
+    /// assert_eq!(paragraph.line_count(15), 1);
+    /// assert_eq!(paragraph.line_count(10), 2);
+    ///
+    /// let state = ScrollbarState::default().content_length(paragraph.line_count(10) as u16);
+    /// ```
+    ///
+    /// [`Scrollbar`]: crate::widgets::Scrollbar
+    pub fn line_count(&self, width: u16) -> usize {
+        let mut line_composer = self.line_composer(self.inner_width(width), 0);
+        let mut count = 0;
+        while line_composer.next_line().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the width of the widest line of the text once laid out in an area of the given
+    /// width.
+    ///
+    /// When [`Paragraph::wrap`] is set, the lines fit in the area (minus the borders and padding
+    /// of the block). Otherwise, the lines are not truncated and this is the length of the content
+    /// to give to a horizontal [`Scrollbar`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::widgets::{Paragraph, Wrap};
+    /// let paragraph = Paragraph::new("Hello, world!");
+    /// assert_eq!(paragraph.line_width(5), 13);
+    /// assert_eq!(paragraph.wrap(Wrap { trim: true }).line_width(10), 6);
+    /// ```
+    ///
+    /// [`Scrollbar`]: crate::widgets::Scrollbar
+    pub fn line_width(&self, width: u16) -> usize {
+        let width = if self.wrap.is_some() {
+            self.inner_width(width)
+        } else {
+            u16::MAX
         };
+        let mut line_composer = self.line_composer(width, 0);
+        let mut max_width = 0;
+        while let Some((_, line_width, _)) = line_composer.next_line() {
+            max_width = max_width.max(line_width as usize);
+        }
+        max_width
+    }
 
-        if text_area.height < 1 {
-            return;
+    /// Returns the width left to the text in an area of the given width
+    fn inner_width(&self, width: u16) -> u16 {
+        match &self.block {
+            Some(b) => b.inner(Rect::new(0, 0, width, 0)).width,
+            None => width,
         }
+    }
 
+    /// Returns the line composer laying out the text in the given width
+    fn line_composer(&self, width: u16, horizontal_offset: u16) -> Box<dyn LineComposer<'_> + '_> {
         let style = self.style;
-        let styled = self.text.lines.iter().map(|line| {
+        let styled = self.text.lines.iter().map(move |line| {
             (
                 line.spans
                     .iter()
-                    .flat_map(|span| span.styled_graphemes(style)),
+                    .flat_map(move |span| span.styled_graphemes(style)),
                 line.alignment.unwrap_or(self.alignment),
             )
         });
 
-        let mut line_composer: Box<dyn LineComposer> = if let Some(Wrap { trim }) = self.wrap {
-            Box::new(WordWrapper::new(styled, text_area.width, trim))
+        if let Some(Wrap { trim }) = self.wrap {
+            let mut line_composer = Box::new(WordWrapper::new(styled, width, trim));
+            line_composer.set_hyphen(self.hyphen);
+            line_composer.set_keep_indentation(self.keep_indentation);
+            line_composer
         } else {
-            let mut line_composer = Box::new(LineTruncator::new(styled, text_area.width));
-            line_composer.set_horizontal_offset(self.scroll.1);
+            let mut line_composer = Box::new(LineTruncator::new(styled, width));
+            line_composer.set_horizontal_offset(horizontal_offset);
+            line_composer.set_ellipsis(self.ellipsis);
             line_composer
+        }
+    }
+}
+
+impl Paragraph<'_> {
+    /// Renders the block and the text scrolled by the given `(y, x)` offsets
+    fn render_scrolled(&self, area: Rect, buf: &mut Buffer, scroll: (u16, u16)) {
+        buf.set_style(area, self.style);
+        let text_area = match &self.block {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
         };
+
+        if text_area.height < 1 {
+            return;
+        }
+
+        let mut line_composer = self.line_composer(text_area.width, scroll.1);
         let mut y = 0;
         while let Some((current_line, current_line_width, current_line_alignment)) =
             line_composer.next_line()
         {
-            if y >= self.scroll.0 {
+            if y >= scroll.0 {
                 let mut x =
                     get_line_offset(current_line_width, text_area.width, current_line_alignment);
-                for StyledGrapheme { symbol, style } in current_line {
+                let line_padding = (current_line_alignment == Alignment::Justify)
+                    .then(|| justify_padding(current_line, current_line_width, text_area.width));
+                let padding = line_padding
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .chain(iter::repeat(0));
+                for (StyledGrapheme { symbol, style }, padding) in current_line.iter().zip(padding)
+                {
                     let width = symbol.width();
                     if width == 0 {
                         continue;
                     }
-                    buf.get_mut(text_area.left() + x, text_area.top() + y - self.scroll.0)
+                    for _ in 0..padding {
+                        buf.get_mut(text_area.left() + x, text_area.top() + y - scroll.0)
+                            .set_symbol(" ")
+                            .set_style(*style);
+                        x += 1;
+                    }
+                    buf.get_mut(text_area.left() + x, text_area.top() + y - scroll.0)
                         .set_symbol(if symbol.is_empty() {
                             // If the symbol is empty, the last char which rendered last time will
                             // leave on the line. It's a quick fix.
@@ -190,13 +479,68 @@ impl<'a> Widget for Paragraph<'a> {
                 }
             }
             y += 1;
-            if y >= text_area.height + self.scroll.0 {
+            if y >= text_area.height + scroll.0 {
                 break;
             }
         }
     }
 }
 
+impl Widget for &Paragraph<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_scrolled(area, buf, self.scroll);
+    }
+}
+
+/// Renders the paragraph scrolled by the offsets of the state, instead of [`Paragraph::scroll`].
+///
+/// The offsets are first clamped so that the last line of the text is not scrolled past the
+/// bottom of the area (nor the widest line past its right edge when the text is not wrapped).
+/// When the state follows the tail of the text, the text is scrolled to the bottom.
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let text_area = match &self.block {
+            Some(b) => b.inner(area),
+            None => area,
+        };
+        // The lines are laid out once to measure them: the unwrapped lines are not truncated so
+        // that the widest one is known, and the wrapped lines fit in the area
+        let width = if self.wrap.is_some() {
+            text_area.width
+        } else {
+            u16::MAX
+        };
+        let mut line_composer = self.line_composer(width, 0);
+        let (mut line_count, mut line_width): (usize, u16) = (0, 0);
+        while let Some((_, width, _)) = line_composer.next_line() {
+            line_count += 1;
+            line_width = line_width.max(width);
+        }
+        let max_y = line_count.saturating_sub(text_area.height as usize);
+        let max_x = line_width.saturating_sub(text_area.width);
+        state.clamp(max_y.min(u16::MAX as usize) as u16, max_x);
+        self.render_scrolled(area, buf, state.offset);
+    }
+}
+
+impl Widget for Paragraph<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl StatefulWidget for Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl_styled!(Paragraph);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -546,6 +890,172 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_justify_alignment() {
+        let text = "The quick brown fox jumps over the dog\naa b cc d";
+        let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Justify);
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+
+        // the lines that are not wrapped are left aligned
+        test_case(
+            &truncated_paragraph,
+            Buffer::with_lines(vec!["The quick br", "aa b cc d   "]),
+        );
+        for paragraph in [&wrapped_paragraph, &trimmed_paragraph] {
+            test_case(
+                paragraph,
+                Buffer::with_lines(vec![
+                    "The    quick",
+                    "brown    fox",
+                    "jumps   over",
+                    "the dog     ",
+                    "aa b cc d   ",
+                ]),
+            );
+        }
+
+        let paragraph = Paragraph::new("aa b cc d")
+            .alignment(Alignment::Justify)
+            .wrap(Wrap { trim: true });
+        test_case(&paragraph, Buffer::with_lines(vec!["aa  b", "cc d "]));
+        test_case(&paragraph, Buffer::with_lines(vec!["aa  b cc", "d       "]));
+    }
+
+    #[test]
+    fn test_wrapped_lines_do_not_overflow() {
+        for (paragraph, width) in [
+            (Paragraph::new("a「。"), 3),
+            (Paragraph::new("well-known").hyphenation("-"), 3),
+            (Paragraph::new("\u{200b}a)"), 1),
+        ] {
+            let paragraph = paragraph.wrap(Wrap { trim: true });
+            assert!(paragraph.line_width(width) <= width as usize);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, 8));
+            Widget::render(&paragraph, buffer.area, &mut buffer);
+        }
+    }
+
+    #[test]
+    fn test_paragraph_line_metrics() {
+        let text = "The quick brown fox\njumps over the lazy dog";
+        let paragraph = Paragraph::new(text);
+        assert_eq!(paragraph.line_count(10), 2);
+        assert_eq!(paragraph.line_width(10), 23);
+
+        let paragraph = paragraph.wrap(Wrap { trim: true });
+        assert_eq!(paragraph.line_count(10), 5);
+        assert_eq!(paragraph.line_width(10), 10);
+        assert_eq!(paragraph.line_count(0), 0);
+        assert_eq!(paragraph.line_width(0), 0);
+
+        // the borders of the block shrink the text area
+        let paragraph = paragraph.block(Block::default().borders(Borders::ALL));
+        assert_eq!(paragraph.line_count(12), 5);
+        assert_eq!(paragraph.line_width(12), 10);
+
+        // the metrics match what is rendered
+        test_case(
+            &paragraph,
+            Buffer::with_lines(vec![
+                "┌──────────┐",
+                "│The quick │",
+                "│brown fox │",
+                "│jumps over│",
+                "│the lazy  │",
+                "│dog       │",
+                "└──────────┘",
+            ]),
+        );
+    }
+
+    /// Renders the paragraph with the given state onto a buffer of the given size
+    fn render_stateful(
+        paragraph: &Paragraph,
+        state: &mut ParagraphState,
+        width: u16,
+        height: u16,
+    ) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(paragraph, buffer.area, &mut buffer, state);
+        buffer
+    }
+
+    #[test]
+    fn test_paragraph_state_clamps_offset() {
+        let paragraph = Paragraph::new("a\nb\nc\nlong line");
+        let mut state = ParagraphState::default().with_offset((10, 10));
+        let buffer = render_stateful(&paragraph, &mut state, 4, 2);
+        assert_eq!(state.offset(), (2, 5));
+        assert_eq!(buffer, Buffer::with_lines(vec!["    ", "line"]));
+
+        // the horizontal offset is ignored when the text is wrapped
+        let paragraph = paragraph.wrap(Wrap { trim: true });
+        let buffer = render_stateful(&paragraph, &mut state, 4, 2);
+        assert_eq!(state.offset(), (2, 0));
+        assert_eq!(buffer, Buffer::with_lines(vec!["c   ", "long"]));
+
+        state.scroll_to_top();
+        render_stateful(&paragraph, &mut state, 4, 2);
+        assert_eq!(state.offset(), (0, 0));
+    }
+
+    #[test]
+    fn test_paragraph_state_follow_tail() {
+        let mut state = ParagraphState::default().with_follow_tail(true);
+        let mut lines = vec!["0", "1", "2"];
+        render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (1, 0));
+
+        // new lines are followed
+        lines.push("3");
+        render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (2, 0));
+        assert!(state.is_following_tail());
+
+        // until the user scrolls up
+        state.scroll_up(1);
+        assert!(!state.is_following_tail());
+        lines.push("4");
+        let buffer = render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (1, 0));
+        assert_eq!(buffer, Buffer::with_lines(vec!["1", "2"]));
+
+        // and scrolls back down to the bottom
+        state.scroll_down(5);
+        render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (3, 0));
+        assert!(state.is_following_tail());
+        lines.push("5");
+        render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (4, 0));
+
+        // without the follow tail mode, the offset stays in place
+        state.set_follow_tail(false);
+        lines.push("6");
+        render_stateful(&Paragraph::new(lines.join("\n")), &mut state, 1, 2);
+        assert_eq!(state.offset(), (4, 0));
+    }
+
+    #[test]
+    fn test_paragraph_state_follow_tail_of_fitting_text() {
+        let paragraph = Paragraph::new("0");
+        let mut state = ParagraphState::default().with_follow_tail(true);
+
+        // scrolling up is kept although the text is already at the bottom
+        state.scroll_up(1);
+        render_stateful(&paragraph, &mut state, 1, 2);
+        assert!(!state.is_following_tail());
+        render_stateful(&paragraph, &mut state, 1, 2);
+        assert!(!state.is_following_tail());
+
+        // until the user scrolls back down
+        state.scroll_down(1);
+        render_stateful(&paragraph, &mut state, 1, 2);
+        assert_eq!(state.offset(), (0, 0));
+        assert!(state.is_following_tail());
+    }
+
     #[test]
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
@@ -681,13 +1191,14 @@ mod test {
             &truncated_paragraph,
             Buffer::with_lines(vec!["こんにちは, 世 "]),
         );
+        // the lines can be broken between ideographs
         test_case(
             &wrapped_paragraph,
-            Buffer::with_lines(vec!["こんにちは,    ", "世界! 😃      "]),
+            Buffer::with_lines(vec!["こんにちは, 世 ", "界! 😃         "]),
         );
         test_case(
             &trimmed_paragraph,
-            Buffer::with_lines(vec!["こんにちは,    ", "世界! 😃      "]),
+            Buffer::with_lines(vec!["こんにちは, 世 ", "界! 😃         "]),
         );
     }
 }
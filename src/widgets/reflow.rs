@@ -1,12 +1,20 @@
 use std::{collections::VecDeque, vec::IntoIter};
 
+use unicode_linebreak::linebreaks;
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
-use crate::{layout::Alignment, text::StyledGrapheme};
+use crate::{
+    layout::Alignment,
+    text::{kept_ranges, DisplayWidth, Ellipsis, StyledGrapheme},
+};
 
 const NBSP: &str = "\u{00a0}";
 
+/// Whether the symbol separates words, non-breaking spaces excluded
+fn is_whitespace(symbol: &str) -> bool {
+    symbol.chars().all(&char::is_whitespace) && symbol != NBSP
+}
+
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
 /// iterators for that).
@@ -15,6 +23,10 @@ pub trait LineComposer<'a> {
 }
 
 /// A state machine that wraps lines on word boundaries.
+///
+/// The lines are broken after whitespace and at the break opportunities of the Unicode line
+/// breaking algorithm (UAX #14), e.g. between CJK ideographs or after a hyphen. Words that do not
+/// fit in a line are split, optionally marking the split with a hyphen.
 pub struct WordWrapper<'a, O, I>
 where
     // Outer iterator providing the individual lines
@@ -31,6 +43,10 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Marker appended to the lines ending with a word split in two
+    hyphen: Option<&'a str>,
+    /// Repeats the indentation of a line on its continuation lines
+    keep_indentation: bool,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
@@ -46,7 +62,203 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            hyphen: None,
+            keep_indentation: false,
+        }
+    }
+
+    pub fn set_hyphen(&mut self, hyphen: Option<&'a str>) {
+        self.hyphen = hyphen;
+    }
+
+    pub fn set_keep_indentation(&mut self, keep_indentation: bool) {
+        self.keep_indentation = keep_indentation;
+    }
+
+    /// Wraps a line, repeating its leading whitespace at the start of each wrapped line
+    fn wrap_indented_line(&self, line_symbols: I) -> Vec<Vec<StyledGrapheme<'a>>> {
+        let mut symbols: Vec<StyledGrapheme<'a>> = line_symbols.collect();
+        let indent_len = symbols
+            .iter()
+            .position(|grapheme| !is_whitespace(grapheme.symbol))
+            .unwrap_or(symbols.len());
+        let indent_width = symbols[..indent_len]
+            .iter()
+            .map(|grapheme| grapheme.symbol.width())
+            .sum::<usize>();
+        if indent_width == 0 || indent_width >= self.max_line_width as usize {
+            return self.wrap_line(symbols, self.max_line_width);
+        }
+        let words = symbols.split_off(indent_len);
+        let max_line_width = self.max_line_width - indent_width as u16;
+        self.wrap_line(words, max_line_width)
+            .into_iter()
+            .map(|line| symbols.iter().cloned().chain(line).collect())
+            .collect()
+    }
+
+    /// Wraps a line into lines fitting in the given width
+    fn wrap_line(
+        &self,
+        line_symbols: Vec<StyledGrapheme<'a>>,
+        max_line_width: u16,
+    ) -> Vec<Vec<StyledGrapheme<'a>>> {
+        let hyphen = self.hyphen.unwrap_or_default();
+        let hyphen_width = hyphen.width() as u16;
+        // The hyphen is only used when there is room left for the text
+        let hyphen_width = if hyphen_width > 0 && hyphen_width < max_line_width {
+            hyphen_width
+        } else {
+            0
+        };
+
+        let mut wrapped_lines = vec![]; // Saves the wrapped lines
+                                        // Saves the unfinished wrapped line
+        let (mut current_line, mut current_line_width) = (vec![], 0);
+        // Saves the partially processed word
+        let (mut unfinished_word, mut word_width) = (vec![], 0);
+        // Saves the whitespaces of the partially unfinished word
+        let (mut unfinished_whitespaces, mut whitespace_width) =
+            (VecDeque::<StyledGrapheme>::new(), 0);
+
+        let break_opportunities = break_opportunities(&line_symbols);
+        let mut has_seen_non_whitespace = false;
+        for (StyledGrapheme { symbol, style }, break_opportunity) in
+            line_symbols.into_iter().zip(break_opportunities)
+        {
+            let symbol_whitespace = is_whitespace(symbol);
+            let symbol_width = symbol.width() as u16;
+            // Ignore characters wider than the total max width
+            if symbol_width > max_line_width {
+                continue;
+            }
+
+            // Whether the line can be broken before the symbol
+            let can_break_before =
+                has_seen_non_whitespace && (symbol_whitespace || break_opportunity);
+            // Room to keep for the hyphen if the word has to be split
+            let reserved_width = if symbol_whitespace || unfinished_word.is_empty() {
+                0
+            } else {
+                hyphen_width
+            };
+            // Whether the word is split in two at the symbol
+            let mut split_word = false;
+
+            // Start a new line if the word does not fit in a line of its own and cannot be broken
+            // before the symbol, so that it gets split below
+            if !can_break_before
+                && !symbol_whitespace
+                && current_line_width > 0
+                && word_width + symbol_width + reserved_width > max_line_width
+            {
+                wrapped_lines.push(std::mem::take(&mut current_line));
+                current_line_width = 0;
+                // The whitespaces before the word end the pushed line
+                unfinished_whitespaces.clear();
+                whitespace_width = 0;
+            }
+
+            // Append finished word to current line
+            if can_break_before
+                // Append if trimmed (whitespaces removed) word would overflow
+                || word_width + symbol_width + reserved_width > max_line_width && current_line_width == 0 && self.trim
+                // Append if removed whitespace would overflow -> reset whitespace counting to prevent overflow
+                || whitespace_width + symbol_width > max_line_width && current_line_width == 0 && self.trim
+                // Append if complete word would overflow
+                || word_width + whitespace_width + symbol_width + reserved_width > max_line_width && current_line_width == 0 && !self.trim
+            {
+                if !current_line.is_empty() || !self.trim {
+                    // Also append whitespaces if not trimming or current line is not
+                    // empty
+                    current_line.extend(std::mem::take(&mut unfinished_whitespaces).into_iter());
+                    current_line_width += whitespace_width;
+                }
+                // Append trimmed word
+                current_line.append(&mut unfinished_word);
+                current_line_width += word_width;
+                // The hyphen is only added when it fits after the first part of the word
+                split_word = !can_break_before
+                    && reserved_width > 0
+                    && current_line_width + hyphen_width <= max_line_width;
+
+                // Clear whitespace buffer
+                unfinished_whitespaces.clear();
+                whitespace_width = 0;
+                word_width = 0;
+            }
+
+            // Append the unfinished wrapped line to wrapped lines if it is as wide as
+            // max line width
+            if current_line_width >= max_line_width
+                // or if it would be too long with the current partially processed word added
+                || current_line_width + whitespace_width + word_width + symbol_width > max_line_width && symbol_width > 0
+                // or if the word is split in two
+                || split_word
+            {
+                let mut remaining_width =
+                    (max_line_width as i32 - current_line_width as i32).max(0) as u16;
+                if split_word {
+                    // The hyphen has the style of the end of the word
+                    let style = current_line.last().map_or(style, |grapheme| grapheme.style);
+                    current_line.extend(
+                        hyphen
+                            .graphemes(true)
+                            .map(|symbol| StyledGrapheme { symbol, style }),
+                    );
+                }
+                wrapped_lines.push(std::mem::take(&mut current_line));
+                current_line_width = 0;
+
+                // Remove all whitespaces till end of just appended wrapped line + next
+                // whitespace
+                let mut first_whitespace = unfinished_whitespaces.pop_front();
+                while let Some(grapheme) = first_whitespace.as_ref() {
+                    let symbol_width = grapheme.symbol.width() as u16;
+                    whitespace_width -= symbol_width;
+
+                    if symbol_width > remaining_width {
+                        break;
+                    }
+                    remaining_width -= symbol_width;
+                    first_whitespace = unfinished_whitespaces.pop_front();
+                }
+                // In case all whitespaces have been exhausted
+                if symbol_whitespace && first_whitespace.is_none() {
+                    // Prevent first whitespace to count towards next word
+                    continue;
+                }
+            }
+
+            // Append symbol to unfinished, partially processed word
+            if symbol_whitespace {
+                whitespace_width += symbol_width;
+                unfinished_whitespaces.push_back(StyledGrapheme { symbol, style });
+            } else {
+                word_width += symbol_width;
+                unfinished_word.push(StyledGrapheme { symbol, style });
+            }
+
+            has_seen_non_whitespace = !symbol_whitespace;
+        }
+
+        // Append remaining text parts
+        if !unfinished_word.is_empty() || !unfinished_whitespaces.is_empty() {
+            if current_line.is_empty() && unfinished_word.is_empty() {
+                wrapped_lines.push(vec![]);
+            } else if !self.trim || !current_line.is_empty() {
+                current_line.extend(unfinished_whitespaces.into_iter());
+            }
+            current_line.append(&mut unfinished_word);
+        }
+        if !current_line.is_empty() {
+            wrapped_lines.push(current_line);
+        }
+        if wrapped_lines.is_empty() {
+            // Append empty line if there was nothing to wrap in the first place
+            wrapped_lines.push(vec![]);
         }
+        wrapped_lines
     }
 }
 
@@ -62,6 +274,7 @@ where
 
         let mut current_line: Option<Vec<StyledGrapheme<'a>>> = None;
         let mut line_width: u16 = 0;
+        let mut is_last_line = false;
 
         // Try to repeatedly retrieve next line
         while current_line.is_none() {
@@ -72,6 +285,7 @@ where
                         .iter()
                         .map(|grapheme| grapheme.symbol.width())
                         .sum::<usize>() as u16;
+                    is_last_line = line_iterator.len() == 0;
                     current_line = Some(line);
                 }
             }
@@ -79,116 +293,14 @@ where
             // When no more preprocessed wrapped lines
             if current_line.is_none() {
                 // Try to calculate next wrapped lines based on current whole line
-                if let Some((line_symbols, line_alignment)) = &mut self.input_lines.next() {
+                if let Some((line_symbols, line_alignment)) = self.input_lines.next() {
                     // Save the whole line's alignment
-                    self.current_alignment = *line_alignment;
-                    let mut wrapped_lines = vec![]; // Saves the wrapped lines
-                                                    // Saves the unfinished wrapped line
-                    let (mut current_line, mut current_line_width) = (vec![], 0);
-                    // Saves the partially processed word
-                    let (mut unfinished_word, mut word_width) = (vec![], 0);
-                    // Saves the whitespaces of the partially unfinished word
-                    let (mut unfinished_whitespaces, mut whitespace_width) =
-                        (VecDeque::<StyledGrapheme>::new(), 0);
-
-                    let mut has_seen_non_whitespace = false;
-                    for StyledGrapheme { symbol, style } in line_symbols {
-                        let symbol_whitespace =
-                            symbol.chars().all(&char::is_whitespace) && symbol != NBSP;
-                        let symbol_width = symbol.width() as u16;
-                        // Ignore characters wider than the total max width
-                        if symbol_width > self.max_line_width {
-                            continue;
-                        }
-
-                        // Append finished word to current line
-                        if has_seen_non_whitespace && symbol_whitespace
-                            // Append if trimmed (whitespaces removed) word would overflow
-                            || word_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
-                            // Append if removed whitespace would overflow -> reset whitespace counting to prevent overflow
-                            || whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
-                            // Append if complete word would overflow
-                            || word_width + whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && !self.trim
-                        {
-                            if !current_line.is_empty() || !self.trim {
-                                // Also append whitespaces if not trimming or current line is not
-                                // empty
-                                current_line.extend(
-                                    std::mem::take(&mut unfinished_whitespaces).into_iter(),
-                                );
-                                current_line_width += whitespace_width;
-                            }
-                            // Append trimmed word
-                            current_line.append(&mut unfinished_word);
-                            current_line_width += word_width;
-
-                            // Clear whitespace buffer
-                            unfinished_whitespaces.clear();
-                            whitespace_width = 0;
-                            word_width = 0;
-                        }
-
-                        // Append the unfinished wrapped line to wrapped lines if it is as wide as
-                        // max line width
-                        if current_line_width >= self.max_line_width
-                            // or if it would be too long with the current partially processed word added
-                            || current_line_width + whitespace_width + word_width >= self.max_line_width && symbol_width > 0
-                        {
-                            let mut remaining_width =
-                                (self.max_line_width as i32 - current_line_width as i32).max(0)
-                                    as u16;
-                            wrapped_lines.push(std::mem::take(&mut current_line));
-                            current_line_width = 0;
-
-                            // Remove all whitespaces till end of just appended wrapped line + next
-                            // whitespace
-                            let mut first_whitespace = unfinished_whitespaces.pop_front();
-                            while let Some(grapheme) = first_whitespace.as_ref() {
-                                let symbol_width = grapheme.symbol.width() as u16;
-                                whitespace_width -= symbol_width;
-
-                                if symbol_width > remaining_width {
-                                    break;
-                                }
-                                remaining_width -= symbol_width;
-                                first_whitespace = unfinished_whitespaces.pop_front();
-                            }
-                            // In case all whitespaces have been exhausted
-                            if symbol_whitespace && first_whitespace.is_none() {
-                                // Prevent first whitespace to count towards next word
-                                continue;
-                            }
-                        }
-
-                        // Append symbol to unfinished, partially processed word
-                        if symbol_whitespace {
-                            whitespace_width += symbol_width;
-                            unfinished_whitespaces.push_back(StyledGrapheme { symbol, style });
-                        } else {
-                            word_width += symbol_width;
-                            unfinished_word.push(StyledGrapheme { symbol, style });
-                        }
-
-                        has_seen_non_whitespace = !symbol_whitespace;
-                    }
-
-                    // Append remaining text parts
-                    if !unfinished_word.is_empty() || !unfinished_whitespaces.is_empty() {
-                        if current_line.is_empty() && unfinished_word.is_empty() {
-                            wrapped_lines.push(vec![]);
-                        } else if !self.trim || !current_line.is_empty() {
-                            current_line.extend(unfinished_whitespaces.into_iter());
-                        }
-                        current_line.append(&mut unfinished_word);
-                    }
-                    if !current_line.is_empty() {
-                        wrapped_lines.push(current_line);
-                    }
-                    if wrapped_lines.is_empty() {
-                        // Append empty line if there was nothing to wrap in the first place
-                        wrapped_lines.push(vec![]);
-                    }
-
+                    self.current_alignment = line_alignment;
+                    let wrapped_lines = if self.keep_indentation {
+                        self.wrap_indented_line(line_symbols)
+                    } else {
+                        self.wrap_line(line_symbols.collect(), self.max_line_width)
+                    };
                     self.wrapped_lines = Some(wrapped_lines.into_iter());
                 } else {
                     // No more whole lines available -> stop repeatedly retrieving next wrapped line
@@ -199,7 +311,12 @@ where
 
         if let Some(line) = current_line {
             self.current_line = line;
-            Some((&self.current_line[..], line_width, self.current_alignment))
+            let alignment = match self.current_alignment {
+                // The last line of a paragraph is not stretched
+                Alignment::Justify if is_last_line => Alignment::Left,
+                alignment => alignment,
+            };
+            Some((&self.current_line[..], line_width, alignment))
         } else {
             None
         }
@@ -221,6 +338,8 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
+    /// Marker replacing the truncated part of the lines
+    ellipsis: Option<Ellipsis<'a>>,
 }
 
 impl<'a, O, I> LineTruncator<'a, O, I>
@@ -234,12 +353,54 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            ellipsis: None,
         }
     }
 
     pub fn set_horizontal_offset(&mut self, horizontal_offset: u16) {
         self.horizontal_offset = horizontal_offset;
     }
+
+    pub fn set_ellipsis(&mut self, ellipsis: Option<Ellipsis<'a>>) {
+        self.ellipsis = ellipsis;
+    }
+
+    /// Fills the current line with the symbols truncated with the ellipsis and returns its width
+    fn truncate_with_ellipsis(&mut self, symbols: &mut I, ellipsis: Ellipsis<'a>) -> u16 {
+        let max_line_width = self.max_line_width as usize;
+        // Ignore characters wider that the total max width.
+        let symbols: Vec<StyledGrapheme<'a>> = symbols
+            .filter(|grapheme| grapheme.symbol.width() <= max_line_width)
+            .collect();
+        let widths: Vec<usize> = symbols
+            .iter()
+            .map(|grapheme| grapheme.symbol.width())
+            .collect();
+        let marker = if ellipsis.symbol.width() <= max_line_width {
+            ellipsis.symbol
+        } else {
+            ""
+        };
+        let Some((head, tail)) =
+            kept_ranges(&widths, max_line_width, marker.width(), ellipsis.position)
+        else {
+            self.current_line = symbols;
+            return widths.iter().sum::<usize>() as u16;
+        };
+
+        let style = symbols[head.end].style;
+        let line_width = widths[head.clone()].iter().sum::<usize>()
+            + marker.width()
+            + widths[tail.clone()].iter().sum::<usize>();
+        self.current_line.extend_from_slice(&symbols[head]);
+        self.current_line.extend(
+            marker
+                .graphemes(true)
+                .map(|symbol| StyledGrapheme { symbol, style }),
+        );
+        self.current_line.extend_from_slice(&symbols[tail]);
+        line_width as u16
+    }
 }
 
 impl<'a, O, I> LineComposer<'a> for LineTruncator<'a, O, I>
@@ -260,34 +421,46 @@ where
         let mut current_alignment = Alignment::Left;
         if let Some((current_line, alignment)) = &mut self.input_lines.next() {
             lines_exhausted = false;
-            current_alignment = *alignment;
+            // Unwrapped lines are the last lines of their paragraph, so they are not justified
+            current_alignment = match *alignment {
+                Alignment::Justify => Alignment::Left,
+                alignment => alignment,
+            };
 
-            for StyledGrapheme { symbol, style } in current_line {
-                // Ignore characters wider that the total max width.
-                if symbol.width() as u16 > self.max_line_width {
-                    continue;
-                }
+            // The ellipsis is ignored when the text is scrolled horizontally
+            let ellipsis = self
+                .ellipsis
+                .filter(|_| horizontal_offset == 0 || current_alignment != Alignment::Left);
+            if let Some(ellipsis) = ellipsis {
+                current_line_width = self.truncate_with_ellipsis(current_line, ellipsis);
+            } else {
+                for StyledGrapheme { symbol, style } in current_line {
+                    // Ignore characters wider that the total max width.
+                    if symbol.width() as u16 > self.max_line_width {
+                        continue;
+                    }
 
-                if current_line_width + symbol.width() as u16 > self.max_line_width {
-                    // Truncate line
-                    break;
-                }
+                    if current_line_width + symbol.width() as u16 > self.max_line_width {
+                        // Truncate line
+                        break;
+                    }
 
-                let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
-                    symbol
-                } else {
-                    let w = symbol.width();
-                    if w > horizontal_offset {
-                        let t = trim_offset(symbol, horizontal_offset);
-                        horizontal_offset = 0;
-                        t
+                    let symbol = if horizontal_offset == 0 || Alignment::Left != current_alignment {
+                        symbol
                     } else {
-                        horizontal_offset -= w;
-                        ""
-                    }
-                };
-                current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                        let w = symbol.width();
+                        if w > horizontal_offset {
+                            let t = trim_offset(symbol, horizontal_offset);
+                            horizontal_offset = 0;
+                            t
+                        } else {
+                            horizontal_offset -= w;
+                            ""
+                        }
+                    };
+                    current_line_width += symbol.width() as u16;
+                    self.current_line.push(StyledGrapheme { symbol, style });
+                }
             }
         }
 
@@ -303,6 +476,47 @@ where
     }
 }
 
+/// Returns whether a line can be broken before each of the graphemes, following the Unicode line
+/// breaking algorithm (UAX #14)
+fn break_opportunities(graphemes: &[StyledGrapheme]) -> Vec<bool> {
+    let text: String = graphemes.iter().map(|grapheme| grapheme.symbol).collect();
+    let mut breaks = linebreaks(&text).map(|(offset, _)| offset).peekable();
+    let mut offset = 0;
+    graphemes
+        .iter()
+        .map(|grapheme| {
+            // Skip the opportunities inside the previous grapheme
+            while breaks.next_if(|&position| position < offset).is_some() {}
+            let opportunity = breaks.peek() == Some(&offset);
+            offset += grapheme.symbol.len();
+            opportunity
+        })
+        .collect()
+}
+
+/// Returns the number of cells to insert before each symbol of `line` so that it fills
+/// `max_line_width`. The extra space is spread across the gaps between words, the leftmost gaps
+/// getting one more cell when it cannot be spread evenly.
+pub fn justify_padding(line: &[StyledGrapheme], line_width: u16, max_line_width: u16) -> Vec<u16> {
+    let mut padding = vec![0; line.len()];
+    let last_word_end = line
+        .iter()
+        .rposition(|grapheme| !is_whitespace(grapheme.symbol))
+        .unwrap_or(0);
+    let gaps: Vec<usize> = (1..last_word_end)
+        .filter(|&i| is_whitespace(line[i].symbol) && !is_whitespace(line[i - 1].symbol))
+        .collect();
+    if gaps.is_empty() {
+        return padding;
+    }
+    let extra = max_line_width.saturating_sub(line_width);
+    let (quotient, remainder) = (extra / gaps.len() as u16, extra as usize % gaps.len());
+    for (n, &i) in gaps.iter().enumerate() {
+        padding[i] = quotient + u16::from(n < remainder);
+    }
+    padding
+}
+
 /// This function will return a str slice which start at specified offset.
 /// As src is a unicode str, start offset has to be calculated with each character.
 fn trim_offset(src: &str, mut offset: usize) -> &str {
@@ -326,7 +540,7 @@ mod test {
     use super::*;
     use crate::{
         style::Style,
-        text::{Line, Text},
+        text::{Ellipsis, Line, Span, Text},
     };
 
     enum Composer {
@@ -572,8 +786,6 @@ mod test {
         let width = 20;
         // Japanese seems not to use spaces but we should break on spaces anyway... We're using it
         // to test double-width chars.
-        // You are more than welcome to add word boundary detection based of alterations of
-        // hiragana and katakana...
         // This happens to also be a test case for mixed width because regular spaces are single
         // width.
         let text = "コンピュ ータ上で文字を扱う場合、 典型的には文 字による 通信を行 う場合にその両端点では、";
@@ -582,16 +794,202 @@ mod test {
         assert_eq!(
             word_wrapper,
             vec![
-                "コンピュ",
-                "ータ上で文字を扱う場",
-                "合、 典型的には文",
-                "字による 通信を行",
-                "う場合にその両端点で",
-                "は、",
+                "コンピュ ータ上で文",
+                "字を扱う場合、 典型",
+                "的には文 字による 通",
+                "信を行 う場合にその",
+                "両端点では、",
             ]
         );
         // Odd-sized lines have a space in them.
-        assert_eq!(word_wrapper_width, vec![8, 20, 17, 17, 20, 4]);
+        assert_eq!(word_wrapper_width, vec![19, 19, 20, 19, 12]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_cjk_punctuation() {
+        // the lines are broken between ideographs, but not before closing punctuation nor after
+        // opening punctuation
+        let text = "日本語の「文章」、句読点。";
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, text, 8);
+        assert_eq!(word_wrapper, vec!["日本語の", "「文", "章」、句", "読点。"]);
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, text, 10);
+        assert_eq!(word_wrapper, vec!["日本語の", "「文章」、", "句読点。"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_breaks_after_hyphens_and_slashes() {
+        let text = "a well-known word";
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, text, 10);
+        assert_eq!(word_wrapper, vec!["a well-", "known word"]);
+        let text = "https://example.com/path/to/page";
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, text, 12);
+        assert_eq!(
+            word_wrapper,
+            vec!["https://", "example.com/", "path/to/page"]
+        );
+        // but not before numbers
+        let (word_wrapper, _, _) =
+            run_composer(Composer::WordWrapper { trim: true }, "aaaa -1 b-2", 6);
+        assert_eq!(word_wrapper, vec!["aaaa", "-1 b-2"]);
+    }
+
+    /// Wraps the text with a `WordWrapper` using the given hyphen and indentation settings
+    fn run_word_wrapper(
+        text: &str,
+        width: u16,
+        hyphen: Option<&str>,
+        keep_indentation: bool,
+    ) -> Vec<String> {
+        let lines = text.split('\n').map(|line| {
+            let graphemes = line.graphemes(true).map(|symbol| StyledGrapheme {
+                symbol,
+                style: Style::default(),
+            });
+            (graphemes, Alignment::Left)
+        });
+        let mut composer = WordWrapper::new(lines, width, true);
+        composer.set_hyphen(hyphen);
+        composer.set_keep_indentation(keep_indentation);
+        let mut wrapped = vec![];
+        while let Some((line, line_width, _)) = composer.next_line() {
+            assert!(line_width <= width);
+            wrapped.push(line.iter().map(|grapheme| grapheme.symbol).collect());
+        }
+        wrapped
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_hyphen() {
+        let text = "abcdefghijklm no";
+        assert_eq!(
+            run_word_wrapper(text, 5, Some("-"), false),
+            vec!["abcd-", "efgh-", "ijkl-", "m no"]
+        );
+        assert_eq!(
+            run_word_wrapper(text, 6, Some("‐"), false),
+            vec!["abcde‐", "fghij‐", "klm no"]
+        );
+        // the hyphen is not used when there is no room for the text
+        assert_eq!(
+            run_word_wrapper("abc", 1, Some("-"), false),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_keep_indentation() {
+        let text = "  - a list item that wraps\nnot indented";
+        assert_eq!(
+            run_word_wrapper(text, 12, None, true),
+            vec!["  - a list", "  item that", "  wraps", "not indented"]
+        );
+        assert_eq!(
+            run_word_wrapper("  abcdefgh", 6, Some("-"), true),
+            vec!["  abc-", "  def-", "  gh"]
+        );
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_lines_fit_in_width() {
+        let texts = [
+            "a「。",
+            "日本語の「文章」、句読点。",
+            "(「a」)、b",
+            "\u{200b}a)",
+            "a\u{200b}bc\u{200b}) d",
+            "well-known",
+            "a well-known non-breaking\u{a0}space",
+            "  indented «quoted» words",
+        ];
+        for text in texts {
+            for width in 1..=4 {
+                for trim in [true, false] {
+                    for hyphen in [None, Some("-"), Some("‐‐")] {
+                        let lines = text.split('\n').map(|line| {
+                            let graphemes = line.graphemes(true).map(|symbol| StyledGrapheme {
+                                symbol,
+                                style: Style::default(),
+                            });
+                            (graphemes, Alignment::Left)
+                        });
+                        let mut composer = WordWrapper::new(lines, width, trim);
+                        composer.set_hyphen(hyphen);
+                        while let Some((line, line_width, _)) = composer.next_line() {
+                            let actual_width = line
+                                .iter()
+                                .map(|grapheme| grapheme.symbol.width())
+                                .sum::<usize>();
+                            assert_eq!(actual_width, line_width as usize);
+                            assert!(
+                                line_width <= width,
+                                "{text:?} wrapped in {width} (trim: {trim}, hyphen: {hyphen:?})"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn run_line_truncator(
+        text: &str,
+        width: u16,
+        ellipsis: Ellipsis,
+        horizontal_offset: u16,
+    ) -> Vec<String> {
+        let lines = text.split('\n').map(|line| {
+            let graphemes = line.graphemes(true).map(|symbol| StyledGrapheme {
+                symbol,
+                style: Style::default(),
+            });
+            (graphemes, Alignment::Left)
+        });
+        let mut composer = LineTruncator::new(lines, width);
+        composer.set_ellipsis(Some(ellipsis));
+        composer.set_horizontal_offset(horizontal_offset);
+        let mut truncated = vec![];
+        while let Some((line, line_width, _)) = composer.next_line() {
+            assert_eq!(
+                line_width as usize,
+                line.iter()
+                    .map(|grapheme| grapheme.symbol.width())
+                    .sum::<usize>()
+            );
+            truncated.push(line.iter().map(|grapheme| grapheme.symbol).collect());
+        }
+        truncated
+    }
+
+    #[test]
+    fn line_composer_line_truncator_ellipsis() {
+        let text = "abcdefgh\nabc";
+        assert_eq!(
+            run_line_truncator(text, 5, Ellipsis::end(), 0),
+            vec!["abcd…", "abc"]
+        );
+        assert_eq!(
+            run_line_truncator(text, 5, Ellipsis::start(), 0),
+            vec!["…efgh", "abc"]
+        );
+        assert_eq!(
+            run_line_truncator("称号abcd称号", 7, Ellipsis::middle(), 0),
+            vec!["称…称号"]
+        );
+        // the marker is split in graphemes and dropped when it does not fit
+        let ellipsis = Ellipsis {
+            symbol: "...",
+            ..Ellipsis::end()
+        };
+        assert_eq!(
+            run_line_truncator(text, 5, ellipsis, 0),
+            vec!["ab...", "abc"]
+        );
+        assert_eq!(run_line_truncator(text, 2, ellipsis, 0), vec!["ab", "ab"]);
+        // the ellipsis is ignored when the text is scrolled horizontally
+        assert_eq!(
+            run_line_truncator(text, 5, Ellipsis::end(), 2),
+            vec!["cdefg", "c"]
+        );
     }
 
     /// Ensure words separated by nbsp are wrapped as if they were a single one.
@@ -687,4 +1085,41 @@ mod test {
             vec![Alignment::Left, Alignment::Right, Alignment::Center]
         );
     }
+
+    #[test]
+    fn line_composer_justify_last_line_is_left_aligned() {
+        let lines = vec![
+            Line::from("Justified text that wraps").alignment(Alignment::Justify),
+            Line::from("Short").alignment(Alignment::Justify),
+        ];
+        let (_, _, wrapped_alignments) =
+            run_composer(Composer::WordWrapper { trim: true }, lines.clone(), 10);
+        let (_, _, truncated_alignments) = run_composer(Composer::LineTruncator, lines, 10);
+        assert_eq!(
+            wrapped_alignments,
+            vec![
+                Alignment::Justify,
+                Alignment::Justify,
+                Alignment::Left,
+                Alignment::Left
+            ]
+        );
+        assert_eq!(truncated_alignments, vec![Alignment::Left, Alignment::Left]);
+    }
+
+    #[test]
+    fn justify_padding_spreads_extra_space() {
+        let span = Span::raw(" a bc  d ");
+        let graphemes: Vec<StyledGrapheme> = span.styled_graphemes(Style::default()).collect();
+        // the leading and trailing whitespaces are not gaps
+        assert_eq!(
+            justify_padding(&graphemes, 9, 12),
+            vec![0, 0, 2, 0, 0, 1, 0, 0, 0]
+        );
+        assert_eq!(justify_padding(&graphemes, 9, 9), vec![0; 9]);
+
+        let span = Span::raw("word");
+        let graphemes: Vec<StyledGrapheme> = span.styled_graphemes(Style::default()).collect();
+        assert_eq!(justify_padding(&graphemes, 4, 10), vec![0; 4]);
+    }
 }
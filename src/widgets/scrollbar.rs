@@ -2,7 +2,7 @@ use super::StatefulWidget;
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::Style,
+    style::{Style, Styled},
     symbols::{block::FULL, line},
 };
 
@@ -428,7 +428,7 @@ impl<'a> Scrollbar<'a> {
     }
 }
 
-impl<'a> StatefulWidget for Scrollbar<'a> {
+impl StatefulWidget for &Scrollbar<'_> {
     type State = ScrollbarState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
@@ -499,6 +499,28 @@ impl<'a> StatefulWidget for Scrollbar<'a> {
     }
 }
 
+impl StatefulWidget for Scrollbar<'_> {
+    type State = ScrollbarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        (&self).render(area, buf, state);
+    }
+}
+
+/// The style of a scrollbar is the style of its track, [`Styled::set_style`] sets the style of all
+/// its parts like [`Scrollbar::style`].
+impl<'a> Styled for Scrollbar<'a> {
+    type Item = Scrollbar<'a>;
+
+    fn style(&self) -> Style {
+        self.track_style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,7 +3,7 @@ use std::cmp::min;
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::Style,
+    style::{impl_styled, Style},
     symbols,
     widgets::{Block, Widget},
 };
@@ -89,9 +89,9 @@ impl<'a> Sparkline<'a> {
     }
 }
 
-impl<'a> Widget for Sparkline<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        let spark_area = match self.block.take() {
+impl Widget for &Sparkline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let spark_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -152,6 +152,14 @@ impl<'a> Widget for Sparkline<'a> {
     }
 }
 
+impl Widget for Sparkline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(Sparkline);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,8 @@
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    style::Style,
-    text::Text,
+    style::{impl_styled, theme, Style},
+    text::{DisplayWidth, Ellipsis, Text},
     widgets::{Block, StatefulWidget, Widget},
 };
 
@@ -205,6 +203,8 @@ pub struct Table<'a> {
     header: Option<Row<'a>>,
     /// Data to display in each row
     rows: Vec<Row<'a>>,
+    /// How the lines wider than their cell are truncated
+    ellipsis: Option<Ellipsis<'a>>,
 }
 
 impl<'a> Table<'a> {
@@ -221,6 +221,7 @@ impl<'a> Table<'a> {
             highlight_symbol: None,
             header: None,
             rows: rows.into_iter().collect(),
+            ellipsis: None,
         }
     }
 
@@ -267,6 +268,12 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Marks the lines of the cells that do not fit in their column with an [`Ellipsis`].
+    pub fn ellipsis(mut self, ellipsis: Ellipsis<'a>) -> Self {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
+
     fn get_columns_widths(&self, max_width: u16, has_selection: bool) -> Vec<u16> {
         let mut constraints = Vec::with_capacity(self.widths.len() * 2 + 1);
         if has_selection {
@@ -373,15 +380,16 @@ impl TableState {
     }
 }
 
-impl<'a> StatefulWidget for Table<'a> {
+impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
-    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         if area.area() == 0 {
             return;
         }
-        buf.set_style(area, self.style);
-        let table_area = match self.block.take() {
+        let theme = theme();
+        buf.set_style(area, theme.base.patch(self.style));
+        let table_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -407,7 +415,7 @@ impl<'a> StatefulWidget for Table<'a> {
                     width: table_area.width,
                     height: table_area.height.min(header.height),
                 },
-                header.style,
+                theme.header.patch(header.style),
             );
             let mut col = table_area.left();
             if has_selection {
@@ -423,6 +431,7 @@ impl<'a> StatefulWidget for Table<'a> {
                         width: *width,
                         height: max_header_height,
                     },
+                    self.ellipsis,
                 );
                 col += *width + self.column_spacing;
             }
@@ -438,7 +447,7 @@ impl<'a> StatefulWidget for Table<'a> {
         state.offset = start;
         for (i, table_row) in self
             .rows
-            .iter_mut()
+            .iter()
             .enumerate()
             .skip(state.offset)
             .take(end - start)
@@ -476,33 +485,55 @@ impl<'a> StatefulWidget for Table<'a> {
                         width: *width,
                         height: table_row.height,
                     },
+                    self.ellipsis,
                 );
                 col += *width + self.column_spacing;
             }
             if is_selected {
-                buf.set_style(table_row_area, self.highlight_style);
+                buf.set_style(table_row_area, theme.selection.patch(self.highlight_style));
             }
         }
     }
 }
 
-fn render_cell(buf: &mut Buffer, cell: &Cell, area: Rect) {
+impl StatefulWidget for Table<'_> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+fn render_cell(buf: &mut Buffer, cell: &Cell, area: Rect, ellipsis: Option<Ellipsis>) {
     buf.set_style(area, cell.style);
     for (i, line) in cell.content.lines.iter().enumerate() {
         if i as u16 >= area.height {
             break;
         }
-        buf.set_line(area.x, area.y + i as u16, line, area.width);
+        if let Some(ellipsis) = ellipsis {
+            let line = line.truncated(area.width as usize, ellipsis);
+            buf.set_line(area.x, area.y + i as u16, &line, area.width);
+        } else {
+            buf.set_line(area.x, area.y + i as u16, line, area.width);
+        }
     }
 }
 
-impl<'a> Widget for Table<'a> {
+impl Widget for &Table<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let mut state = TableState::default();
         StatefulWidget::render(self, area, buf, &mut state);
     }
 }
 
+impl Widget for Table<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl_styled!(Cell, Row, Table);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -512,4 +543,19 @@ mod tests {
     fn table_invalid_percentages() {
         Table::new(vec![]).widths(&[Constraint::Percentage(110)]);
     }
+
+    #[test]
+    fn table_render_by_reference() {
+        let table = Table::new(vec![Row::new(vec!["a", "b"]), Row::new(vec!["c", "d"])])
+            .widths(&[Constraint::Length(2), Constraint::Length(2)]);
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buffer = Buffer::empty(area);
+        Widget::render(&table, area, &mut buffer);
+        let expected = Buffer::with_lines(vec!["a  b ", "c  d "]);
+        assert_eq!(buffer, expected);
+
+        let mut buffer = Buffer::empty(area);
+        Widget::render(&table, area, &mut buffer);
+        assert_eq!(buffer, expected);
+    }
 }
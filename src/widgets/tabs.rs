@@ -1,9 +1,9 @@
 use crate::{
     buffer::Buffer,
     layout::Rect,
-    style::Style,
+    style::{impl_styled, theme, Style},
     symbols,
-    text::{Line, Span},
+    text::{Ellipsis, Line, Span},
     widgets::{Block, Widget},
 };
 
@@ -37,6 +37,8 @@ pub struct Tabs<'a> {
     highlight_style: Style,
     /// Tab divider
     divider: Span<'a>,
+    /// How the title overflowing the tabs is truncated
+    ellipsis: Option<Ellipsis<'a>>,
 }
 
 impl<'a> Tabs<'a> {
@@ -51,6 +53,7 @@ impl<'a> Tabs<'a> {
             style: Style::default(),
             highlight_style: Style::default(),
             divider: Span::raw(symbols::line::VERTICAL),
+            ellipsis: None,
         }
     }
 
@@ -81,12 +84,19 @@ impl<'a> Tabs<'a> {
         self.divider = divider.into();
         self
     }
+
+    /// Marks the title that does not fit in the remaining width with an [`Ellipsis`].
+    pub fn ellipsis(mut self, ellipsis: Ellipsis<'a>) -> Tabs<'a> {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
 }
 
-impl<'a> Widget for Tabs<'a> {
-    fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let tabs_area = match self.block.take() {
+impl Widget for &Tabs<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = theme();
+        buf.set_style(area, theme.base.patch(self.style));
+        let tabs_area = match &self.block {
             Some(b) => {
                 let inner_area = b.inner(area);
                 b.render(area, buf);
@@ -101,14 +111,19 @@ impl<'a> Widget for Tabs<'a> {
 
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
-        for (i, title) in self.titles.into_iter().enumerate() {
+        for (i, title) in self.titles.iter().enumerate() {
             let last_title = titles_length - 1 == i;
             x = x.saturating_add(1);
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
                 break;
             }
-            let pos = buf.set_line(x, tabs_area.top(), &title, remaining_width);
+            let pos = if let Some(ellipsis) = self.ellipsis {
+                let title = title.truncated(remaining_width as usize, ellipsis);
+                buf.set_line(x, tabs_area.top(), &title, remaining_width)
+            } else {
+                buf.set_line(x, tabs_area.top(), title, remaining_width)
+            };
             if i == self.selected {
                 buf.set_style(
                     Rect {
@@ -117,7 +132,7 @@ impl<'a> Widget for Tabs<'a> {
                         width: pos.0.saturating_sub(x),
                         height: 1,
                     },
-                    self.highlight_style,
+                    theme.highlight.patch(self.highlight_style),
                 );
             }
             x = pos.0.saturating_add(1);
@@ -130,3 +145,11 @@ impl<'a> Widget for Tabs<'a> {
         }
     }
 }
+
+impl Widget for Tabs<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl_styled!(Tabs);
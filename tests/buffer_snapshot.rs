@@ -0,0 +1,28 @@
+use ratatui::{
+    assert_buffer_snapshot,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+#[test]
+fn buffer_snapshot_matches_file() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+    let block = Block::default()
+        .title("Title")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+    let paragraph = Paragraph::new("Hello")
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .block(block);
+    paragraph.render(buffer.area, &mut buffer);
+    assert_buffer_snapshot!(buffer, "block_with_paragraph");
+}
+
+#[test]
+fn buffer_snapshot_file_round_trip() {
+    let snapshot = include_str!("snapshots/block_with_paragraph.snap");
+    let buffer = Buffer::from_snapshot(snapshot).unwrap();
+    assert_eq!(buffer.to_snapshot(), snapshot);
+}
@@ -0,0 +1,77 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{set_theme, theme, Color, Modifier, Style, Theme},
+    text::Line,
+    widgets::{
+        render_offscreen, render_stateful_offscreen, Block, Borders, Gauge, List, ListItem,
+        ListState, Row, Table, Tabs,
+    },
+};
+
+/// The theme is global, so all the checks are done in a single test to avoid races.
+#[test]
+fn widgets_use_the_current_theme() {
+    assert_eq!(theme(), Theme::default());
+    let base = Style::default().bg(Color::Black);
+    set_theme(Theme {
+        base,
+        border: Style::default().fg(Color::DarkGray),
+        title: Style::default().add_modifier(Modifier::BOLD),
+        header: Style::default().fg(Color::Yellow),
+        highlight: Style::default().fg(Color::Green),
+        selection: Style::default().add_modifier(Modifier::REVERSED),
+        ..Theme::default()
+    });
+
+    // block, with an explicit border style patched on top of the theme
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Hi")
+        .border_style(Style::default().fg(Color::Red));
+    let mut expected = Buffer::with_lines(vec!["┌Hi────┐", "│      │", "└──────┘"]);
+    expected.set_style(expected.area, base.fg(Color::Red));
+    expected.set_style(Rect::new(1, 1, 6, 1), Style::default().fg(Color::Reset));
+    expected.set_style(
+        Rect::new(1, 0, 2, 1),
+        Style::default().add_modifier(Modifier::BOLD),
+    );
+    assert_eq!(render_offscreen(block, 8, 3), expected);
+
+    // list
+    let list = List::new(vec![ListItem::new("a"), ListItem::new("b")]);
+    let mut state = ListState::default();
+    state.select(Some(1));
+    let mut expected = Buffer::with_lines(vec!["a  ", "b  "]);
+    expected.set_style(expected.area, base);
+    expected.set_style(
+        Rect::new(0, 1, 3, 1),
+        Style::default().add_modifier(Modifier::REVERSED),
+    );
+    assert_eq!(render_stateful_offscreen(list, 3, 2, &mut state), expected);
+
+    // table
+    let table = Table::new(vec![Row::new(vec!["x"])])
+        .header(Row::new(vec!["h"]))
+        .widths(&[Constraint::Length(2)]);
+    let mut expected = Buffer::with_lines(vec!["h ", "x "]);
+    expected.set_style(expected.area, base);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Yellow));
+    assert_eq!(render_offscreen(table, 2, 2), expected);
+
+    // tabs
+    let tabs = Tabs::new(vec![Line::from("A"), Line::from("B")]);
+    let buffer = render_offscreen(tabs, 7, 1);
+    assert_eq!(buffer.get(1, 0).style(), base.fg(Color::Green));
+    assert_eq!(buffer.get(5, 0).style(), base.fg(Color::Reset));
+
+    // gauge
+    let gauge = Gauge::default().ratio(0.0);
+    let buffer = render_offscreen(gauge, 4, 1);
+    assert_eq!(buffer.get(0, 0).style(), base.fg(Color::Green));
+
+    // swapping the theme at runtime
+    set_theme(Theme::default());
+    let buffer = render_offscreen(Block::default().borders(Borders::ALL), 2, 2);
+    assert_eq!(buffer, Buffer::with_lines(vec!["┌┐", "└┘"]));
+}
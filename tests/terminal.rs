@@ -2,8 +2,11 @@ use std::error::Error;
 
 use ratatui::{
     backend::{Backend, TestBackend},
+    buffer::Buffer,
     layout::Rect,
-    widgets::Paragraph,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{render_offscreen, Paragraph},
     Terminal,
 };
 
@@ -23,9 +26,9 @@ fn swap_buffer_clears_prev_buffer() {
     terminal
         .current_buffer_mut()
         .set_string(0, 0, "Hello", ratatui::style::Style::reset());
-    assert_eq!(terminal.current_buffer_mut().content()[0].symbol, "H");
+    assert_eq!(terminal.current_buffer_mut().content()[0].symbol(), "H");
     terminal.swap_buffers();
-    assert_eq!(terminal.current_buffer_mut().content()[0].symbol, " ");
+    assert_eq!(terminal.current_buffer_mut().content()[0].symbol(), " ");
 }
 
 #[test]
@@ -36,14 +39,128 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
         let paragraph = Paragraph::new("Test");
         f.render_widget(paragraph, f.size());
     })?;
-    assert_eq!(frame.buffer.get(0, 0).symbol, "T");
+    assert_eq!(frame.buffer.get(0, 0).symbol(), "T");
     assert_eq!(frame.area, Rect::new(0, 0, 10, 10));
     terminal.backend_mut().resize(8, 8);
     let frame = terminal.draw(|f| {
         let paragraph = Paragraph::new("test");
         f.render_widget(paragraph, f.size());
     })?;
-    assert_eq!(frame.buffer.get(0, 0).symbol, "t");
+    assert_eq!(frame.buffer.get(0, 0).symbol(), "t");
     assert_eq!(frame.area, Rect::new(0, 0, 8, 8));
     Ok(())
 }
+
+#[test]
+fn terminal_draw_records_the_frame_history() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2).with_history_capacity(2);
+    let mut terminal = Terminal::new(backend)?;
+    for text in ["one", "two", "three"] {
+        terminal.draw(|f| {
+            f.render_widget(Paragraph::new(text), f.size());
+            f.set_cursor(text.len() as u16, 1);
+        })?;
+    }
+    terminal.draw(|f| f.render_widget(Paragraph::new("four"), f.size()))?;
+
+    let history: Vec<_> = terminal.backend().history().collect();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].buffer.get(0, 0).symbol(), "t");
+    assert_eq!(history[0].cursor_position, (5, 1));
+    assert!(history[0].cursor_visible);
+    assert_eq!(history[1].buffer.get(0, 0).symbol(), "f");
+    assert!(!history[1].cursor_visible);
+    assert_eq!(terminal.backend().last_frame(), Some(history[1]));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_cursor_assertions() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    terminal.draw(|f| f.set_cursor(3, 1))?;
+    terminal.backend().assert_cursor_visible_at(3, 1);
+    terminal.draw(|_| {})?;
+    terminal.backend().assert_cursor_hidden();
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "does not have the expected style")]
+fn terminal_draw_cell_style_assertion() {
+    let mut terminal = Terminal::new(TestBackend::new(5, 1)).unwrap();
+    terminal
+        .draw(|f| {
+            let paragraph = Paragraph::new("Hello").style(Style::default().fg(Color::Red));
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    let backend = terminal.backend();
+    backend.assert_cell_style(4, 0, Style::default().fg(Color::Red));
+    backend.assert_cell_style(4, 0, Style::default().remove_modifier(Modifier::BOLD));
+    backend.assert_cell_style(4, 0, Style::default().bg(Color::Blue));
+}
+
+#[test]
+fn terminal_draw_styled_snapshot() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    terminal.draw(|f| {
+        let text = Line::from(vec![
+            Span::raw("a "),
+            Span::styled("bold", Style::default().add_modifier(Modifier::BOLD)),
+        ]);
+        f.render_widget(Paragraph::new(text), f.size());
+        f.render_widget(
+            Paragraph::new("styled").style(Style::default().fg(Color::Red).bg(Color::Blue)),
+            Rect::new(0, 1, 6, 1),
+        );
+    })?;
+    terminal.backend().assert_styled_snapshot(
+        r#"
+        "a bold    "
+        "styled    "
+        styles:
+        "..aaaa...."
+        "bbbbbb...."
+        legend:
+        a: modifier: BOLD
+        b: fg: Red, bg: Blue
+        "#,
+    );
+    Ok(())
+}
+
+#[test]
+fn terminal_resume_redraws_everything() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("Hello"), f.size()))?;
+
+    terminal.suspend()?;
+    terminal.backend().assert_cursor_visible_at(0, 0);
+    // another program uses the terminal in the meantime
+    terminal.backend_mut().clear()?;
+    terminal.backend_mut().resize(8, 3);
+    terminal.resume()?;
+
+    terminal.draw(|f| f.render_widget(Paragraph::new("Hello"), f.size()))?;
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "Hello   ", "        ", "        ",
+    ]));
+    Ok(())
+}
+
+#[test]
+fn terminal_render_buffer_is_clipped() -> Result<(), Box<dyn Error>> {
+    let cached = render_offscreen(
+        Paragraph::new(vec![Line::from("abcdef"), Line::from("ghijkl")]),
+        6,
+        2,
+    );
+    let mut terminal = Terminal::new(TestBackend::new(6, 3))?;
+    terminal.draw(|f| {
+        f.render_buffer(&cached, Rect::new(1, 1, 3, 5));
+    })?;
+    terminal
+        .backend()
+        .assert_buffer(&Buffer::with_lines(vec!["      ", " abc  ", " ghi  "]));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{set_width_strategy, width_strategy, Line, WidthStrategy},
+    widgets::{Paragraph, Wrap},
+    Terminal,
+};
+
+/// The width strategy is global, so all the checks are done in a single test to avoid races.
+#[test]
+fn width_strategy_is_used_consistently() {
+    let degree = "°";
+    assert_eq!(width_strategy(), WidthStrategy::default());
+    assert_eq!(Line::from(degree).width(), 1);
+
+    set_width_strategy(WidthStrategy {
+        ambiguous_as_wide: true,
+        emoji_as_wide: true,
+    });
+    assert_eq!(Line::from(degree).width(), 2);
+    assert_eq!(Line::from("±1").width(), 3);
+    assert_eq!(Line::from("☺\u{FE0F}✓").width(), 3);
+
+    // buffer
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+    let end = buffer.set_stringn(0, 0, format!("{degree}±"), 4, Style::default());
+    assert_eq!(end, (4, 0));
+    assert_eq!(buffer.get(0, 0).symbol(), degree);
+    assert_eq!(buffer.get(1, 0).symbol(), " ");
+    assert_eq!(buffer.get(2, 0).symbol(), "±");
+
+    // reflow
+    let mut terminal = Terminal::new(TestBackend::new(4, 2)).unwrap();
+    terminal
+        .draw(|f| {
+            let text = format!("{degree}± a");
+            let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+            f.render_widget(paragraph, f.size());
+        })
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    assert_eq!(buffer.get(0, 0).symbol(), degree);
+    assert_eq!(buffer.get(2, 0).symbol(), "±");
+    assert_eq!(buffer.get(0, 1).symbol(), "a");
+
+    set_width_strategy(WidthStrategy::default());
+}
@@ -6,7 +6,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     symbols,
-    text::Line,
+    text::{Ellipsis, Line},
     widgets::{Block, Borders, List, ListItem, ListState},
     Terminal,
 };
@@ -106,6 +106,29 @@ fn widgets_list_should_truncate_items() {
     }
 }
 
+#[test]
+fn widgets_list_should_truncate_items_with_an_ellipsis() {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut state = ListState::default();
+    state.select(Some(0));
+    terminal
+        .draw(|f| {
+            let items = vec![ListItem::new("A very long line"), ListItem::new("Item")];
+            let list = List::new(items)
+                .block(Block::default().borders(Borders::RIGHT))
+                .highlight_symbol(">> ")
+                .ellipsis(Ellipsis::end());
+            f.render_stateful_widget(list, Rect::new(0, 0, 8, 2), &mut state);
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![
+        format!(">> A v…{}  ", symbols::line::VERTICAL),
+        format!("   Item{}  ", symbols::line::VERTICAL),
+    ]);
+    terminal.backend().assert_buffer(&expected);
+}
+
 #[test]
 fn widgets_list_should_clamp_offset_if_items_are_removed() {
     let backend = TestBackend::new(10, 4);
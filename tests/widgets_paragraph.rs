@@ -38,14 +38,15 @@ fn widgets_paragraph_renders_double_width_graphemes() {
         paragraph,
         Buffer::with_lines(vec![
             "┌────────┐",
-            "│コンピュ│",
-            "│ータ上で│",
-            "│文字を扱│",
-            "│う場合、│",
-            "│典型的に│",
-            "│は文字に│",
-            "│よる通信│",
-            "│を行う場│",
+            // small kana and the prolonged sound mark cannot start a line
+            "│コン    │",
+            "│ピュータ│",
+            "│上で文字│",
+            "│を扱う場│",
+            "│合、典型│",
+            "│的には文│",
+            "│字による│",
+            "│通信を行│",
             "└────────┘",
         ]),
     );
@@ -71,11 +72,11 @@ fn widgets_paragraph_renders_mixed_width_graphemes() {
     let expected = Buffer::with_lines(vec![
         // The internal width is 8 so only 4 slots for double-width characters.
         "┌────────┐",
-        "│aコンピ │", // Here we have 1 latin character so only 3 double-width ones can fit.
-        "│ュータ上│",
-        "│で文字を│",
-        "│扱う場合│",
-        "│、      │",
+        "│aコン   │", // "ピュー" cannot be split, so it does not fit after 1 latin character.
+        "│ピュータ│",
+        "│上で文字│",
+        "│を扱う場│",
+        "│合、    │", // Commas cannot start a line.
         "└────────┘",
     ]);
     terminal.backend().assert_buffer(&expected);
@@ -5,7 +5,7 @@ use ratatui::{
     buffer::Buffer,
     layout::Constraint,
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{Ellipsis, Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Terminal,
 };
@@ -823,3 +823,22 @@ fn widgets_table_should_clamp_offset_if_rows_are_removed() {
     ]);
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_table_cells_can_be_truncated_with_an_ellipsis() {
+    let backend = TestBackend::new(14, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let size = f.size();
+            let table = Table::new(vec![Row::new(vec!["~/src/main.rs", "status"])])
+                .header(Row::new(vec!["Path", "State"]))
+                .widths(&[Constraint::Length(8), Constraint::Length(5)])
+                .ellipsis(Ellipsis::middle());
+            f.render_widget(table, size);
+        })
+        .unwrap();
+
+    let expected = Buffer::with_lines(vec!["Path     State", "~/sr….rs st…us"]);
+    terminal.backend().assert_buffer(&expected);
+}
@@ -1,7 +1,12 @@
 #![allow(deprecated)]
 
 use ratatui::{
-    backend::TestBackend, buffer::Buffer, layout::Rect, symbols, text::Line, widgets::Tabs,
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    symbols,
+    text::{Ellipsis, Line},
+    widgets::Tabs,
     Terminal,
 };
 
@@ -48,3 +53,18 @@ fn widgets_tabs_should_truncate_the_last_item() {
     let expected = Buffer::with_lines(vec![format!(" Tab1 {} T ", symbols::line::VERTICAL)]);
     terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn widgets_tabs_should_truncate_the_last_item_with_an_ellipsis() {
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            let tabs = Tabs::new(["Tab1", "Tab2"].iter().cloned().map(Line::from).collect())
+                .ellipsis(Ellipsis::end());
+            f.render_widget(tabs, Rect::new(0, 0, 10, 1));
+        })
+        .unwrap();
+    let expected = Buffer::with_lines(vec![format!(" Tab1 {} T…", symbols::line::VERTICAL)]);
+    terminal.backend().assert_buffer(&expected);
+}